@@ -1,7 +1,13 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 
 declare_id!("CSRftWGDWFCbwvib9s6XbnqJheuSR5eVPmieKGDJmA7Y");
 
+/// Seed of the PDA an allowlisted program signs with when calling
+/// `update_reputation` via CPI
+pub const REPUTATION_AUTHORITY_SEED: &[u8] = b"reputation_authority";
+
 #[program]
 pub mod question_bank {
     use super::*;
@@ -18,7 +24,17 @@ pub mod question_bank {
         question_bank.total_questions = 0;
         question_bank.active_questions = 0;
         question_bank.curators = vec![authority]; // Authority is initial curator
+        question_bank.reputation_callers = Vec::new();
         question_bank.bump = ctx.bumps.question_bank;
+
+        let bank_config = &mut ctx.accounts.bank_config;
+        bank_config.set_inner(BankConfig::default());
+        bank_config.bump = ctx.bumps.bank_config;
+
+        let curation_pool = &mut ctx.accounts.curation_pool;
+        curation_pool.total_forfeited = 0;
+        curation_pool.total_withdrawn = 0;
+        curation_pool.bump = ctx.bumps.curation_pool;
         
         msg!("Question Bank initialized with authority: {}", authority);
         Ok(())
@@ -30,39 +46,34 @@ pub mod question_bank {
         ctx: Context<SubmitQuestion>,
         question_data: QuestionData,
     ) -> Result<()> {
-        // Validate question format
-        require!(
-            question_data.question_text.len() <= 500,
-            QuestionBankError::InvalidQuestionFormat
-        );
-        require!(
-            question_data.category.len() <= 50,
-            QuestionBankError::InvalidQuestionFormat
-        );
+        let bank_config = &ctx.accounts.bank_config;
+
+        validate_question_data(&question_data, bank_config)?;
+
+        // Check if user has sufficient reputation to submit
+        let user_reputation = &ctx.accounts.user_reputation;
         require!(
-            question_data.difficulty >= 1 && question_data.difficulty <= 3,
-            QuestionBankError::InvalidQuestionFormat
+            user_reputation.reputation_score >= bank_config.min_submit_reputation,
+            QuestionBankError::InsufficientReputation
         );
         require!(
-            question_data.correct_answer <= 3,
-            QuestionBankError::InvalidQuestionFormat
+            user_reputation.pending_questions < bank_config.max_pending_questions,
+            QuestionBankError::TooManyPendingQuestions
         );
 
-        // Validate each option length
-        for option in &question_data.options {
-            require!(
-                option.len() <= 100,
-                QuestionBankError::InvalidQuestionFormat
+        // Escrow the submission bond in the question account itself
+        let bond = bank_config.submission_bond;
+        if bond > 0 {
+            let cpi_context = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.submitter.to_account_info(),
+                    to: ctx.accounts.question.to_account_info(),
+                },
             );
+            anchor_lang::system_program::transfer(cpi_context, bond)?;
         }
 
-        // Check if user has sufficient reputation (minimum 100 for new questions)
-        let user_reputation = &ctx.accounts.user_reputation;
-        require!(
-            user_reputation.reputation_score >= 100,
-            QuestionBankError::InsufficientReputation
-        );
-
         let question = &mut ctx.accounts.question;
         let question_bank = &mut ctx.accounts.question_bank;
         
@@ -71,14 +82,24 @@ pub mod question_bank {
         question.submitter = ctx.accounts.submitter.key();
         question.question_text = question_data.question_text;
         question.options = question_data.options;
-        question.correct_answer = question_data.correct_answer;
+        question.question_type = question_data.question_type;
         question.category = question_data.category;
         question.difficulty = question_data.difficulty;
         question.votes_approve = 0;
         question.votes_reject = 0;
-        question.voters = Vec::new();
+        question.weight_approve = 0;
+        question.weight_reject = 0;
+        question.stake_approve = 0;
+        question.stake_reject = 0;
+        question.stake_slash_bps = 0;
         question.status = QuestionStatus::Pending;
         question.created_at = Clock::get()?.unix_timestamp;
+        question.voting_deadline = question.created_at + bank_config.voting_period;
+        question.bond = bond;
+        question.revision = 0;
+        question.previous_revision_hash = [0; 32];
+        question.finalized_revision = 0;
+        question.flag_count = 0;
         question.bump = ctx.bumps.question;
 
         // Update counters
@@ -87,6 +108,7 @@ pub mod question_bank {
         // Update user reputation for submission
         let user_reputation = &mut ctx.accounts.user_reputation;
         user_reputation.questions_submitted += 1;
+        user_reputation.pending_questions += 1;
 
         msg!(
             "Question submitted by: {}, ID: {}, Category: {}",
@@ -99,7 +121,7 @@ pub mod question_bank {
     }
 
     /// Vote on a submitted question (approve or reject)
-    /// Implements double-voting prevention and self-voting restriction
+    /// Each vote is stored in its own PDA, so voting twice fails on `init`
     pub fn vote_on_question(
         ctx: Context<VoteOnQuestion>,
         vote_type: VoteType,
@@ -112,6 +134,10 @@ pub mod question_bank {
             question.status == QuestionStatus::Pending,
             QuestionBankError::QuestionNotPending
         );
+        require!(
+            Clock::get()?.unix_timestamp <= question.voting_deadline,
+            QuestionBankError::VotingClosed
+        );
 
         // Prevent users from voting on their own questions
         require!(
@@ -119,42 +145,189 @@ pub mod question_bank {
             QuestionBankError::CannotVoteOnOwnQuestion
         );
 
-        // Check for double voting
-        require!(
-            !question.voters.contains(&voter),
-            QuestionBankError::AlreadyVoted
+        // Weight comes from reputation before this vote's reward
+        let bank_config = &ctx.accounts.bank_config;
+        let weight = vote_weight(
+            &bank_config.vote_weighting,
+            &bank_config.vote_tiers,
+            ctx.accounts.user_reputation.reputation_score,
         );
 
-        // Add voter to the list
-        question.voters.push(voter);
+        // Lock the configured stake, crediting what the vault actually received
+        let mut stake = 0;
+        if bank_config.vote_stake > 0 {
+            let (Some(voter_token_account), Some(stake_vault), Some(stake_mint), Some(token_program)) = (
+                ctx.accounts.voter_token_account.as_ref(),
+                ctx.accounts.stake_vault.as_mut(),
+                ctx.accounts.stake_mint.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return err!(QuestionBankError::MissingStakeAccounts);
+            };
+
+            let vault_balance_before = stake_vault.amount;
+
+            let cpi_accounts = TransferChecked {
+                from: voter_token_account.to_account_info(),
+                mint: stake_mint.to_account_info(),
+                to: stake_vault.to_account_info(),
+                authority: ctx.accounts.voter.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+
+            token_interface::transfer_checked(cpi_ctx, bank_config.vote_stake, stake_mint.decimals)?;
+
+            stake_vault.reload()?;
+            stake = stake_vault
+                .amount
+                .checked_sub(vault_balance_before)
+                .ok_or(QuestionBankError::ArithmeticOverflow)?;
+        }
+
+        // Record the vote
+        let vote_record = &mut ctx.accounts.vote_record;
+        vote_record.question = question.key();
+        vote_record.voter = voter;
+        vote_record.vote_type = vote_type.clone();
+        vote_record.weight = weight;
+        vote_record.stake = stake;
+        vote_record.stake_claimed = false;
+        vote_record.revision = question.revision;
+        vote_record.voted_at = Clock::get()?.unix_timestamp;
+        vote_record.bump = ctx.bumps.vote_record;
 
         // Update vote counts
         match vote_type {
             VoteType::Approve => {
                 question.votes_approve += 1;
+                question.weight_approve += weight;
+                question.stake_approve += stake;
             }
             VoteType::Reject => {
                 question.votes_reject += 1;
+                question.weight_reject += weight;
+                question.stake_reject += stake;
             }
         }
 
         // Update voter's reputation
         let user_reputation = &mut ctx.accounts.user_reputation;
         user_reputation.curation_votes += 1;
-        user_reputation.reputation_score += 10; // Small reward for participation
+        user_reputation.reputation_score += bank_config.vote_reward; // Small reward for participation
 
         msg!(
-            "Vote recorded: {:?} by {} for question {}",
+            "Vote recorded: {:?} by {} for question {} (weight {})",
             vote_type,
             voter,
-            question.id
+            question.id,
+            weight
+        );
+
+        Ok(())
+    }
+
+    /// Edit a pending question's content
+    /// Bumps the revision, records a hash of the replaced content and resets
+    /// the tallies so votes only ever count for the text that goes live
+    pub fn edit_question(
+        ctx: Context<EditQuestion>,
+        question_data: QuestionData,
+    ) -> Result<()> {
+        let bank_config = &ctx.accounts.bank_config;
+        let question = &mut ctx.accounts.question;
+
+        require!(
+            question.status == QuestionStatus::Pending,
+            QuestionBankError::QuestionNotPending
+        );
+        let now = Clock::get()?.unix_timestamp;
+        require!(now <= question.voting_deadline, QuestionBankError::VotingClosed);
+
+        validate_question_data(&question_data, bank_config)?;
+
+        question.previous_revision_hash = question.content_hash();
+        question.revision += 1;
+        question.question_text = question_data.question_text;
+        question.options = question_data.options;
+        question.question_type = question_data.question_type;
+        question.category = question_data.category;
+        question.difficulty = question_data.difficulty;
+
+        // Earlier votes become stale; their voters can re-vote on this revision
+        question.votes_approve = 0;
+        question.votes_reject = 0;
+        question.weight_approve = 0;
+        question.weight_reject = 0;
+        question.stake_approve = 0;
+        question.stake_reject = 0;
+        question.voting_deadline = now + bank_config.voting_period;
+
+        msg!(
+            "Question {} edited to revision {}",
+            question.id,
+            question.revision
+        );
+        Ok(())
+    }
+
+    /// Re-cast a vote left stale by `edit_question`
+    /// Any locked stake carries over; no participation reward is paid again
+    pub fn revote_on_question(
+        ctx: Context<RevoteOnQuestion>,
+        vote_type: VoteType,
+    ) -> Result<()> {
+        let question = &mut ctx.accounts.question;
+        let vote_record = &mut ctx.accounts.vote_record;
+
+        require!(
+            question.status == QuestionStatus::Pending,
+            QuestionBankError::QuestionNotPending
+        );
+        require!(
+            Clock::get()?.unix_timestamp <= question.voting_deadline,
+            QuestionBankError::VotingClosed
+        );
+        require!(
+            vote_record.revision < question.revision,
+            QuestionBankError::VoteNotStale
+        );
+
+        let weight = vote_weight(
+            &ctx.accounts.bank_config.vote_weighting,
+            &ctx.accounts.bank_config.vote_tiers,
+            ctx.accounts.user_reputation.reputation_score,
         );
 
+        vote_record.vote_type = vote_type.clone();
+        vote_record.weight = weight;
+        vote_record.revision = question.revision;
+        vote_record.voted_at = Clock::get()?.unix_timestamp;
+
+        match vote_type {
+            VoteType::Approve => {
+                question.votes_approve += 1;
+                question.weight_approve += weight;
+                question.stake_approve += vote_record.stake;
+            }
+            VoteType::Reject => {
+                question.votes_reject += 1;
+                question.weight_reject += weight;
+                question.stake_reject += vote_record.stake;
+            }
+        }
+
+        msg!(
+            "Vote re-cast: {:?} by {} for question {} revision {}",
+            vote_type,
+            vote_record.voter,
+            question.id,
+            question.revision
+        );
         Ok(())
     }
 
     /// Finalize a question's status based on votes
-    /// Only approved curators can finalize questions
+    /// Curators can finalize during voting; anyone can once the deadline passes
     pub fn finalize_question(
         ctx: Context<FinalizeQuestion>,
         question_id: u64,
@@ -162,10 +335,11 @@ pub mod question_bank {
         let question = &mut ctx.accounts.question;
         let question_bank = &mut ctx.accounts.question_bank;
         let curator = ctx.accounts.curator.key();
+        let voting_closed = Clock::get()?.unix_timestamp > question.voting_deadline;
         
-        // Verify that the caller is an approved curator
+        // Before the deadline only approved curators can finalize
         require!(
-            question_bank.curators.contains(&curator),
+            voting_closed || question_bank.curators.contains(&curator),
             QuestionBankError::UnauthorizedCurator
         );
 
@@ -180,29 +354,61 @@ pub mod question_bank {
         );
 
         // Determine final status based on votes
+        let bank_config = &ctx.accounts.bank_config;
         let total_votes = question.votes_approve + question.votes_reject;
-        require!(total_votes >= 5, QuestionBankError::InsufficientVotes); // Minimum 5 votes required
+        let total_weight = question.weight_approve + question.weight_reject;
+        let quorum_reached =
+            total_votes >= bank_config.min_votes && total_weight >= bank_config.weighted_quorum;
+
+        if voting_closed && !quorum_reached {
+            // Not enough participation: expire without penalizing the submitter
+            question.status = QuestionStatus::Expired;
+            refund_bond(question, &ctx.accounts.submitter)?;
+        } else {
+            require!(total_votes >= bank_config.min_votes, QuestionBankError::InsufficientVotes);
 
-        if question.votes_approve > question.votes_reject {
-            question.status = QuestionStatus::Approved;
-            question_bank.active_questions += 1;
+            // Approval is decided by reputation-weighted tallies
+            require!(
+                total_weight >= bank_config.weighted_quorum,
+                QuestionBankError::InsufficientVoteWeight
+            );
+
+            // Lock in the slash rate and revision that vote stakes settle at
+            question.stake_slash_bps = bank_config.stake_slash_bps;
+            question.finalized_revision = question.revision;
 
-            // Update submitter's reputation for approved question
-            let submitter_reputation = &mut ctx.accounts.submitter_reputation;
-            submitter_reputation.questions_approved += 1;
-            submitter_reputation.reputation_score += 50; // Reward for approved question
-        } else {
-            question.status = QuestionStatus::Rejected;
-            
-            // Slight reputation penalty for rejected question
             let submitter_reputation = &mut ctx.accounts.submitter_reputation;
-            if submitter_reputation.reputation_score > 10 {
-                submitter_reputation.reputation_score -= 10;
+            if question.weight_approve > question.weight_reject {
+                question.status = QuestionStatus::Approved;
+                question_bank.active_questions += 1;
+
+                // Update submitter's reputation for approved question
+                submitter_reputation.questions_approved += 1;
+                submitter_reputation.reputation_score += bank_config.approval_reward; // Reward for approved question
+                refund_bond(question, &ctx.accounts.submitter)?;
+            } else {
+                question.status = QuestionStatus::Rejected;
+
+                // Slight reputation penalty for rejected question
+                if submitter_reputation.reputation_score > bank_config.rejection_penalty {
+                    submitter_reputation.reputation_score -= bank_config.rejection_penalty;
+                }
+
+                // Forfeit the bond to the curation reward pool
+                let bond = question.bond;
+                question.bond = 0;
+                let curation_pool = &mut ctx.accounts.curation_pool;
+                curation_pool.total_forfeited += bond;
+                **question.to_account_info().try_borrow_mut_lamports()? -= bond;
+                **curation_pool.to_account_info().try_borrow_mut_lamports()? += bond;
             }
         }
 
+        let submitter_reputation = &mut ctx.accounts.submitter_reputation;
+        submitter_reputation.pending_questions = submitter_reputation.pending_questions.saturating_sub(1);
+
         msg!(
-            "Question {} finalized with status: {:?} by curator {}",
+            "Question {} finalized with status: {:?} by {}",
             question_id,
             question.status,
             curator
@@ -211,31 +417,219 @@ pub mod question_bank {
         Ok(())
     }
 
+    /// Flag an approved question as wrong or outdated, escrowing a bond
+    /// Moves the question into `UnderReview` until curators resolve the flag
+    pub fn flag_question(
+        ctx: Context<FlagQuestion>,
+        reason: String,
+        proposed_answer: Option<QuestionType>,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.question.status == QuestionStatus::Approved,
+            QuestionBankError::QuestionNotApproved
+        );
+        require!(
+            !reason.is_empty() && reason.len() <= QuestionFlag::MAX_REASON_LEN,
+            QuestionBankError::InvalidFlagReason
+        );
+        if let Some(proposed_answer) = &proposed_answer {
+            validate_question_type(proposed_answer, ctx.accounts.question.options.len())?;
+        }
+
+        // Escrow the flag bond in the flag account itself
+        let bond = ctx.accounts.bank_config.flag_bond;
+        if bond > 0 {
+            let cpi_context = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.flagger.to_account_info(),
+                    to: ctx.accounts.flag.to_account_info(),
+                },
+            );
+            anchor_lang::system_program::transfer(cpi_context, bond)?;
+        }
+
+        let question = &mut ctx.accounts.question;
+        let flag = &mut ctx.accounts.flag;
+        flag.question = question.key();
+        flag.flagger = ctx.accounts.flagger.key();
+        flag.reason = reason;
+        flag.proposed_answer = proposed_answer;
+        flag.bond = bond;
+        flag.votes_restore = 0;
+        flag.votes_fix = 0;
+        flag.votes_retire = 0;
+        flag.outcome = None;
+        flag.created_at = Clock::get()?.unix_timestamp;
+        flag.bump = ctx.bumps.flag;
+
+        question.status = QuestionStatus::UnderReview;
+        question.flag_count += 1;
+
+        msg!("Question {} flagged by {}", question.id, flag.flagger);
+        Ok(())
+    }
+
+    /// Cast a curator's vote on how to resolve a flagged question
+    /// Each vote is stored in its own PDA, so voting twice fails on `init`
+    pub fn vote_on_review(
+        ctx: Context<VoteOnReview>,
+        outcome: ReviewOutcome,
+    ) -> Result<()> {
+        let curator = ctx.accounts.curator.key();
+        require!(
+            ctx.accounts.question_bank.curators.contains(&curator),
+            QuestionBankError::UnauthorizedCurator
+        );
+        require!(
+            ctx.accounts.question.status == QuestionStatus::UnderReview,
+            QuestionBankError::NotUnderReview
+        );
+
+        let flag = &mut ctx.accounts.flag;
+        match outcome {
+            ReviewOutcome::Restore => flag.votes_restore += 1,
+            ReviewOutcome::Fix => {
+                require!(
+                    flag.proposed_answer.is_some(),
+                    QuestionBankError::NoProposedFix
+                );
+                flag.votes_fix += 1;
+            }
+            ReviewOutcome::Retire => flag.votes_retire += 1,
+        }
+
+        let review_vote = &mut ctx.accounts.review_vote;
+        review_vote.flag = flag.key();
+        review_vote.curator = curator;
+        review_vote.outcome = outcome.clone();
+        review_vote.bump = ctx.bumps.review_vote;
+
+        msg!(
+            "Review vote {:?} by curator {} for question {}",
+            outcome,
+            curator,
+            ctx.accounts.question.id
+        );
+        Ok(())
+    }
+
+    /// Apply the curators' decision on a flagged question
+    /// Anyone can resolve once enough review votes are in
+    pub fn resolve_review(ctx: Context<ResolveReview>) -> Result<()> {
+        let bank_config = &ctx.accounts.bank_config;
+        let question = &mut ctx.accounts.question;
+        let flag = &mut ctx.accounts.flag;
+
+        require!(
+            question.status == QuestionStatus::UnderReview,
+            QuestionBankError::NotUnderReview
+        );
+        let total_votes = flag.votes_restore + flag.votes_fix + flag.votes_retire;
+        require!(
+            total_votes >= bank_config.min_review_votes,
+            QuestionBankError::InsufficientReviewVotes
+        );
+
+        // Ties favour the least disruptive outcome
+        let outcome = if flag.votes_restore >= flag.votes_fix && flag.votes_restore >= flag.votes_retire {
+            ReviewOutcome::Restore
+        } else if flag.votes_fix >= flag.votes_retire {
+            ReviewOutcome::Fix
+        } else {
+            ReviewOutcome::Retire
+        };
+
+        let bond = flag.bond;
+        flag.bond = 0;
+        match outcome {
+            ReviewOutcome::Restore => {
+                question.status = QuestionStatus::Approved;
+
+                // The flag was unfounded, so its bond funds curation rewards
+                let curation_pool = &mut ctx.accounts.curation_pool;
+                curation_pool.total_forfeited += bond;
+                **flag.to_account_info().try_borrow_mut_lamports()? -= bond;
+                **curation_pool.to_account_info().try_borrow_mut_lamports()? += bond;
+            }
+            ReviewOutcome::Fix => {
+                // Only the answer changes, recorded as a new revision
+                question.previous_revision_hash = question.content_hash();
+                question.revision += 1;
+                question.question_type = flag
+                    .proposed_answer
+                    .clone()
+                    .ok_or(QuestionBankError::NoProposedFix)?;
+                question.status = QuestionStatus::Approved;
+
+                **flag.to_account_info().try_borrow_mut_lamports()? -= bond;
+                **ctx.accounts.flagger.try_borrow_mut_lamports()? += bond;
+            }
+            ReviewOutcome::Retire => {
+                question.status = QuestionStatus::Retired;
+                let question_bank = &mut ctx.accounts.question_bank;
+                question_bank.active_questions = question_bank.active_questions.saturating_sub(1);
+
+                // Reverse the submitter's approval reward
+                let submitter_reputation = &mut ctx.accounts.submitter_reputation;
+                submitter_reputation.questions_approved =
+                    submitter_reputation.questions_approved.saturating_sub(1);
+                submitter_reputation.reputation_score = submitter_reputation
+                    .reputation_score
+                    .saturating_sub(bank_config.approval_reward);
+
+                **flag.to_account_info().try_borrow_mut_lamports()? -= bond;
+                **ctx.accounts.flagger.try_borrow_mut_lamports()? += bond;
+            }
+        }
+        flag.outcome = Some(outcome);
+
+        msg!(
+            "Review of question {} resolved: {:?}",
+            question.id,
+            flag.outcome
+        );
+        Ok(())
+    }
+
     /// Update user reputation based on various actions
-    /// Internal function called by other instructions
+    /// Only the authority, or an allowlisted program signing with its
+    /// `REPUTATION_AUTHORITY_SEED` PDA, can call this
     pub fn update_reputation(
         ctx: Context<UpdateReputation>,
         action_type: ReputationAction,
     ) -> Result<()> {
+        let question_bank = &ctx.accounts.question_bank;
+        let caller = ctx.accounts.caller.key();
+
+        require!(
+            caller == question_bank.authority
+                || question_bank.reputation_callers.iter().any(|program_id| {
+                    Pubkey::find_program_address(&[REPUTATION_AUTHORITY_SEED], program_id).0 == caller
+                }),
+            QuestionBankError::UnauthorizedReputationCaller
+        );
+
+        let bank_config = &ctx.accounts.bank_config;
         let user_reputation = &mut ctx.accounts.user_reputation;
         
         match action_type {
             ReputationAction::QuestionSubmitted => {
                 user_reputation.questions_submitted += 1;
-                user_reputation.reputation_score += 5;
+                user_reputation.reputation_score += bank_config.submission_reward;
             }
             ReputationAction::QuestionApproved => {
                 user_reputation.questions_approved += 1;
-                user_reputation.reputation_score += 50;
+                user_reputation.reputation_score += bank_config.approval_reward;
             }
             ReputationAction::QuestionRejected => {
-                if user_reputation.reputation_score > 10 {
-                    user_reputation.reputation_score -= 10;
+                if user_reputation.reputation_score > bank_config.rejection_penalty {
+                    user_reputation.reputation_score -= bank_config.rejection_penalty;
                 }
             }
             ReputationAction::VoteCast => {
                 user_reputation.curation_votes += 1;
-                user_reputation.reputation_score += 10;
+                user_reputation.reputation_score += bank_config.vote_reward;
             }
         }
 
@@ -276,6 +670,238 @@ pub mod question_bank {
         Ok(())
     }
 
+    /// Update curation thresholds and rewards
+    /// Only the authority can update the bank configuration
+    pub fn update_bank_config(
+        ctx: Context<UpdateBankConfig>,
+        update: BankConfigUpdate,
+    ) -> Result<()> {
+        // Verify authority
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.question_bank.authority,
+            QuestionBankError::UnauthorizedAuthority
+        );
+
+        let bank_config = &mut ctx.accounts.bank_config;
+
+        if let Some(min_submit_reputation) = update.min_submit_reputation {
+            bank_config.min_submit_reputation = min_submit_reputation;
+        }
+        if let Some(initial_reputation) = update.initial_reputation {
+            bank_config.initial_reputation = initial_reputation;
+        }
+        if let Some(min_votes) = update.min_votes {
+            bank_config.min_votes = min_votes;
+        }
+        if let Some(voting_period) = update.voting_period {
+            bank_config.voting_period = voting_period;
+        }
+        if let Some(weighted_quorum) = update.weighted_quorum {
+            bank_config.weighted_quorum = weighted_quorum;
+        }
+        if let Some(vote_weighting) = update.vote_weighting {
+            bank_config.vote_weighting = vote_weighting;
+        }
+        if let Some(vote_tiers) = update.vote_tiers {
+            bank_config.vote_tiers = vote_tiers;
+        }
+        if let Some(submission_reward) = update.submission_reward {
+            bank_config.submission_reward = submission_reward;
+        }
+        if let Some(vote_reward) = update.vote_reward {
+            bank_config.vote_reward = vote_reward;
+        }
+        if let Some(approval_reward) = update.approval_reward {
+            bank_config.approval_reward = approval_reward;
+        }
+        if let Some(rejection_penalty) = update.rejection_penalty {
+            bank_config.rejection_penalty = rejection_penalty;
+        }
+        if let Some(max_question_len) = update.max_question_len {
+            bank_config.max_question_len = max_question_len;
+        }
+        if let Some(max_option_len) = update.max_option_len {
+            bank_config.max_option_len = max_option_len;
+        }
+        if let Some(max_category_len) = update.max_category_len {
+            bank_config.max_category_len = max_category_len;
+        }
+        if let Some(min_difficulty) = update.min_difficulty {
+            bank_config.min_difficulty = min_difficulty;
+        }
+        if let Some(max_difficulty) = update.max_difficulty {
+            bank_config.max_difficulty = max_difficulty;
+        }
+        if let Some(submission_bond) = update.submission_bond {
+            bank_config.submission_bond = submission_bond;
+        }
+        if let Some(max_pending_questions) = update.max_pending_questions {
+            bank_config.max_pending_questions = max_pending_questions;
+        }
+        if let Some(flag_bond) = update.flag_bond {
+            bank_config.flag_bond = flag_bond;
+        }
+        if let Some(min_review_votes) = update.min_review_votes {
+            bank_config.min_review_votes = min_review_votes;
+        }
+        if let Some(vote_stake) = update.vote_stake {
+            bank_config.vote_stake = vote_stake;
+        }
+        if let Some(stake_slash_bps) = update.stake_slash_bps {
+            bank_config.stake_slash_bps = stake_slash_bps;
+        }
+
+        bank_config.validate()?;
+
+        msg!("Bank config updated by {}", ctx.accounts.authority.key());
+        Ok(())
+    }
+
+    /// Pay out forfeited submission bonds from the curation reward pool
+    /// Only the authority can withdraw
+    pub fn withdraw_curation_rewards(
+        ctx: Context<WithdrawCurationRewards>,
+        amount: u64,
+    ) -> Result<()> {
+        // Verify authority
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.question_bank.authority,
+            QuestionBankError::UnauthorizedAuthority
+        );
+
+        let curation_pool = &mut ctx.accounts.curation_pool;
+        let available = curation_pool.total_forfeited - curation_pool.total_withdrawn;
+        require!(amount <= available, QuestionBankError::InsufficientPoolBalance);
+
+        curation_pool.total_withdrawn += amount;
+        **curation_pool.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.recipient.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        msg!(
+            "Withdrew {} lamports of curation rewards to {}",
+            amount,
+            ctx.accounts.recipient.key()
+        );
+        Ok(())
+    }
+
+    /// Create the vault that holds vote stakes and fix the stake mint
+    /// Only the authority can call this, and only once
+    pub fn initialize_stake_vault(ctx: Context<InitializeStakeVault>) -> Result<()> {
+        // Verify authority
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.question_bank.authority,
+            QuestionBankError::UnauthorizedAuthority
+        );
+
+        let bank_config = &mut ctx.accounts.bank_config;
+        bank_config.stake_mint = ctx.accounts.stake_mint.key();
+
+        msg!("Stake vault initialized for mint {}", bank_config.stake_mint);
+        Ok(())
+    }
+
+    /// Settle a vote's stake once its question is finalized
+    /// Winners get their stake plus a share of the slashed losing stake,
+    /// losers get their stake minus the slash, expired questions refund in full
+    pub fn claim_vote_stake(ctx: Context<ClaimVoteStake>) -> Result<()> {
+        let question = &ctx.accounts.question;
+        let vote_record = &mut ctx.accounts.vote_record;
+
+        require!(
+            question.status != QuestionStatus::Pending,
+            QuestionBankError::QuestionNotFinalized
+        );
+        require!(vote_record.stake > 0, QuestionBankError::NoStake);
+        require!(!vote_record.stake_claimed, QuestionBankError::StakeAlreadyClaimed);
+
+        // Votes left on an older revision never counted, so they are refunded
+        let payout = if vote_record.revision == question.finalized_revision {
+            stake_payout(question, vote_record.stake, &vote_record.vote_type)
+        } else {
+            vote_record.stake
+        };
+        vote_record.stake_claimed = true;
+
+        if payout > 0 {
+            let seeds = &[b"bank_config".as_ref(), &[ctx.accounts.bank_config.bump]];
+            let signer = &[&seeds[..]];
+
+            let cpi_accounts = TransferChecked {
+                from: ctx.accounts.stake_vault.to_account_info(),
+                mint: ctx.accounts.stake_mint.to_account_info(),
+                to: ctx.accounts.voter_token_account.to_account_info(),
+                authority: ctx.accounts.bank_config.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+            token_interface::transfer_checked(cpi_ctx, payout, ctx.accounts.stake_mint.decimals)?;
+        }
+
+        msg!(
+            "Vote stake settled for {} on question {}: staked {}, paid {}",
+            vote_record.voter,
+            question.id,
+            vote_record.stake,
+            payout
+        );
+        Ok(())
+    }
+
+    /// Allow a program to update reputation via CPI
+    /// Only the authority can add reputation callers
+    pub fn add_reputation_caller(
+        ctx: Context<AddCurator>,
+        program_id: Pubkey,
+    ) -> Result<()> {
+        let question_bank = &mut ctx.accounts.question_bank;
+        
+        // Verify authority
+        require!(
+            ctx.accounts.authority.key() == question_bank.authority,
+            QuestionBankError::UnauthorizedAuthority
+        );
+
+        require!(
+            !question_bank.reputation_callers.contains(&program_id),
+            QuestionBankError::ReputationCallerAlreadyExists
+        );
+        require!(
+            question_bank.reputation_callers.len() < QuestionBank::MAX_REPUTATION_CALLERS,
+            QuestionBankError::TooManyReputationCallers
+        );
+
+        question_bank.reputation_callers.push(program_id);
+
+        msg!("Reputation caller added: {}", program_id);
+        Ok(())
+    }
+
+    /// Revoke a program's permission to update reputation
+    /// Only the authority can remove reputation callers
+    pub fn remove_reputation_caller(
+        ctx: Context<RemoveCurator>,
+        program_id: Pubkey,
+    ) -> Result<()> {
+        let question_bank = &mut ctx.accounts.question_bank;
+        
+        // Verify authority
+        require!(
+            ctx.accounts.authority.key() == question_bank.authority,
+            QuestionBankError::UnauthorizedAuthority
+        );
+
+        if let Some(pos) = question_bank.reputation_callers.iter().position(|&x| x == program_id) {
+            question_bank.reputation_callers.remove(pos);
+            msg!("Reputation caller removed: {}", program_id);
+        } else {
+            return Err(QuestionBankError::ReputationCallerNotFound.into());
+        }
+
+        Ok(())
+    }
+
     /// Remove a curator from the Question Bank
     /// Only the authority can remove curators
     pub fn remove_curator(
@@ -318,7 +944,8 @@ pub mod question_bank {
         user_reputation.questions_submitted = 0;
         user_reputation.questions_approved = 0;
         user_reputation.curation_votes = 0;
-        user_reputation.reputation_score = 100; // Starting reputation
+        user_reputation.pending_questions = 0;
+        user_reputation.reputation_score = ctx.accounts.bank_config.initial_reputation; // Starting reputation
         user_reputation.bump = ctx.bumps.user_reputation;
 
         msg!("User reputation initialized for: {}", user_reputation.user);
@@ -358,11 +985,144 @@ pub mod question_bank {
     }
 }
 
-// ============================================================================
-// Account Contexts
-// ============================================================================
-
-#[derive(Accounts)]
+/// Check question content against the configured format limits
+fn validate_question_data(question_data: &QuestionData, bank_config: &BankConfig) -> Result<()> {
+    require!(
+        question_data.question_text.len() <= bank_config.max_question_len as usize,
+        QuestionBankError::InvalidQuestionFormat
+    );
+    require!(
+        question_data.category.len() <= bank_config.max_category_len as usize,
+        QuestionBankError::InvalidQuestionFormat
+    );
+    require!(
+        question_data.difficulty >= bank_config.min_difficulty
+            && question_data.difficulty <= bank_config.max_difficulty,
+        QuestionBankError::InvalidQuestionFormat
+    );
+    require!(
+        question_data.options.len() <= Question::MAX_OPTIONS,
+        QuestionBankError::InvalidQuestionFormat
+    );
+
+    // Validate each option length
+    for option in &question_data.options {
+        require!(
+            !option.is_empty() && option.len() <= bank_config.max_option_len as usize,
+            QuestionBankError::InvalidQuestionFormat
+        );
+    }
+
+    validate_question_type(&question_data.question_type, question_data.options.len())
+}
+
+/// Check an answer key against the number of options its question has
+fn validate_question_type(question_type: &QuestionType, option_count: usize) -> Result<()> {
+    let valid = match question_type {
+        // True/false and numeric questions carry no options
+        QuestionType::TrueFalse { .. } | QuestionType::Numeric { .. } => option_count == 0,
+        QuestionType::MultipleChoice { correct_index } => {
+            (Question::MIN_OPTIONS..=Question::MAX_OPTIONS).contains(&option_count)
+                && (*correct_index as usize) < option_count
+        }
+        QuestionType::MultiSelect { correct_mask } => {
+            (Question::MIN_OPTIONS..=Question::MAX_OPTIONS).contains(&option_count)
+                && *correct_mask != 0
+                && (*correct_mask as u32) < (1 << option_count)
+        }
+    };
+    require!(valid, QuestionBankError::InvalidQuestionFormat);
+    Ok(())
+}
+
+/// Whether `answer` is correct for a question of this type
+/// Answers are encoded as 0/1 for true/false, the option index for multiple
+/// choice, the bitmask of chosen options for multi-select, or the value itself
+/// for numeric questions
+pub fn check_answer(question_type: &QuestionType, answer: i64) -> bool {
+    match question_type {
+        QuestionType::TrueFalse { answer: correct } => answer == *correct as i64,
+        QuestionType::MultipleChoice { correct_index } => answer == *correct_index as i64,
+        QuestionType::MultiSelect { correct_mask } => answer == *correct_mask as i64,
+        QuestionType::Numeric { answer: correct, tolerance } => answer.abs_diff(*correct) <= *tolerance,
+    }
+}
+
+/// Return a question's escrowed submission bond to its submitter
+fn refund_bond(question: &mut Account<Question>, submitter: &AccountInfo) -> Result<()> {
+    let bond = question.bond;
+    question.bond = 0;
+    **question.to_account_info().try_borrow_mut_lamports()? -= bond;
+    **submitter.try_borrow_mut_lamports()? += bond;
+    Ok(())
+}
+
+/// Amount returned to a voter for `stake` on a finalized question
+/// Winners share the slashed losing stake pro rata; rounding dust stays in the vault
+pub fn stake_payout(question: &Question, stake: u64, vote_type: &VoteType) -> u64 {
+    // Later reviews don't change how the original curation vote settles
+    let approved = matches!(
+        question.status,
+        QuestionStatus::Approved | QuestionStatus::UnderReview | QuestionStatus::Retired
+    );
+    let (winning_stake, losing_stake, won) = match (approved, &question.status, vote_type) {
+        (true, _, VoteType::Approve) => (question.stake_approve, question.stake_reject, true),
+        (true, _, VoteType::Reject) => (question.stake_approve, question.stake_reject, false),
+        (false, QuestionStatus::Rejected, VoteType::Reject) => (question.stake_reject, question.stake_approve, true),
+        (false, QuestionStatus::Rejected, VoteType::Approve) => (question.stake_reject, question.stake_approve, false),
+        // Nothing was decided, so nobody is slashed
+        _ => return stake,
+    };
+
+    let slash_bps = question.stake_slash_bps as u128;
+    if won {
+        if winning_stake == 0 {
+            return stake;
+        }
+        let slashed_pool = losing_stake as u128 * slash_bps / 10_000;
+        let share = slashed_pool * stake as u128 / winning_stake as u128;
+        stake + share as u64
+    } else {
+        stake - (stake as u128 * slash_bps / 10_000) as u64
+    }
+}
+
+/// Vote weight of a voter with the given reputation score
+pub fn vote_weight(
+    vote_weighting: &VoteWeighting,
+    vote_tiers: &[u64; 3],
+    reputation_score: u64,
+) -> u64 {
+    match vote_weighting {
+        VoteWeighting::Linear => reputation_score,
+        VoteWeighting::SquareRoot => integer_sqrt(reputation_score),
+        // One weight per tier threshold reached
+        VoteWeighting::Tiered => vote_tiers
+            .iter()
+            .filter(|&&threshold| reputation_score >= threshold)
+            .count() as u64,
+    }
+}
+
+fn integer_sqrt(value: u64) -> u64 {
+    if value < 2 {
+        return value;
+    }
+    // Newton's method, starting above the root
+    let mut x = value / 2 + 1;
+    let mut y = (x + value / x) / 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+// ============================================================================
+// Account Contexts
+// ============================================================================
+
+#[derive(Accounts)]
 pub struct InitializeQuestionBank<'info> {
     #[account(
         init,
@@ -373,6 +1133,24 @@ pub struct InitializeQuestionBank<'info> {
     )]
     pub question_bank: Account<'info, QuestionBank>,
     
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BankConfig::SPACE,
+        seeds = [b"bank_config"],
+        bump
+    )]
+    pub bank_config: Account<'info, BankConfig>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CurationPool::SPACE,
+        seeds = [b"curation_pool"],
+        bump
+    )]
+    pub curation_pool: Account<'info, CurationPool>,
+    
     #[account(mut)]
     pub payer: Signer<'info>,
     
@@ -397,6 +1175,12 @@ pub struct SubmitQuestion<'info> {
     )]
     pub question_bank: Account<'info, QuestionBank>,
     
+    #[account(
+        seeds = [b"bank_config"],
+        bump = bank_config.bump
+    )]
+    pub bank_config: Account<'info, BankConfig>,
+    
     #[account(
         mut,
         seeds = [b"reputation", submitter.key().as_ref()],
@@ -419,6 +1203,21 @@ pub struct VoteOnQuestion<'info> {
     )]
     pub question: Account<'info, Question>,
     
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteRecord::SPACE,
+        seeds = [b"vote", question.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    #[account(
+        seeds = [b"bank_config"],
+        bump = bank_config.bump
+    )]
+    pub bank_config: Account<'info, BankConfig>,
+    
     #[account(
         mut,
         seeds = [b"reputation", voter.key().as_ref()],
@@ -426,8 +1225,193 @@ pub struct VoteOnQuestion<'info> {
     )]
     pub user_reputation: Account<'info, UserReputation>,
     
+    /// Stake accounts, required while `bank_config.vote_stake` is nonzero
+    #[account(mut)]
+    pub voter_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake_vault"],
+        bump
+    )]
+    pub stake_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(address = bank_config.stake_mint @ QuestionBankError::InvalidStakeMint)]
+    pub stake_mint: Option<InterfaceAccount<'info, Mint>>,
+    
     #[account(mut)]
     pub voter: Signer<'info>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct EditQuestion<'info> {
+    #[account(
+        mut,
+        seeds = [b"question", question.id.to_le_bytes().as_ref()],
+        bump = question.bump,
+        has_one = submitter @ QuestionBankError::NotQuestionSubmitter
+    )]
+    pub question: Account<'info, Question>,
+    
+    #[account(
+        seeds = [b"bank_config"],
+        bump = bank_config.bump
+    )]
+    pub bank_config: Account<'info, BankConfig>,
+    
+    pub submitter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RevoteOnQuestion<'info> {
+    #[account(
+        mut,
+        seeds = [b"question", question.id.to_le_bytes().as_ref()],
+        bump = question.bump
+    )]
+    pub question: Account<'info, Question>,
+    
+    #[account(
+        mut,
+        seeds = [b"vote", question.key().as_ref(), voter.key().as_ref()],
+        bump = vote_record.bump,
+        has_one = voter
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    #[account(
+        seeds = [b"bank_config"],
+        bump = bank_config.bump
+    )]
+    pub bank_config: Account<'info, BankConfig>,
+    
+    #[account(
+        seeds = [b"reputation", voter.key().as_ref()],
+        bump = user_reputation.bump
+    )]
+    pub user_reputation: Account<'info, UserReputation>,
+    
+    pub voter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FlagQuestion<'info> {
+    #[account(
+        mut,
+        seeds = [b"question", question.id.to_le_bytes().as_ref()],
+        bump = question.bump
+    )]
+    pub question: Account<'info, Question>,
+    
+    #[account(
+        init,
+        payer = flagger,
+        space = 8 + QuestionFlag::SPACE,
+        seeds = [b"flag", question.key().as_ref(), question.flag_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub flag: Account<'info, QuestionFlag>,
+    
+    #[account(
+        seeds = [b"bank_config"],
+        bump = bank_config.bump
+    )]
+    pub bank_config: Account<'info, BankConfig>,
+    
+    #[account(mut)]
+    pub flagger: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VoteOnReview<'info> {
+    #[account(
+        seeds = [b"question", question.id.to_le_bytes().as_ref()],
+        bump = question.bump
+    )]
+    pub question: Account<'info, Question>,
+    
+    /// The question's latest flag
+    #[account(
+        mut,
+        seeds = [b"flag", question.key().as_ref(), question.flag_count.saturating_sub(1).to_le_bytes().as_ref()],
+        bump = flag.bump
+    )]
+    pub flag: Account<'info, QuestionFlag>,
+    
+    #[account(
+        init,
+        payer = curator,
+        space = 8 + ReviewVote::SPACE,
+        seeds = [b"review_vote", flag.key().as_ref(), curator.key().as_ref()],
+        bump
+    )]
+    pub review_vote: Account<'info, ReviewVote>,
+    
+    #[account(
+        seeds = [b"question_bank"],
+        bump = question_bank.bump
+    )]
+    pub question_bank: Account<'info, QuestionBank>,
+    
+    #[account(mut)]
+    pub curator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveReview<'info> {
+    #[account(
+        mut,
+        seeds = [b"question", question.id.to_le_bytes().as_ref()],
+        bump = question.bump
+    )]
+    pub question: Account<'info, Question>,
+    
+    /// The question's latest flag
+    #[account(
+        mut,
+        seeds = [b"flag", question.key().as_ref(), question.flag_count.saturating_sub(1).to_le_bytes().as_ref()],
+        bump = flag.bump
+    )]
+    pub flag: Account<'info, QuestionFlag>,
+    
+    #[account(
+        mut,
+        seeds = [b"question_bank"],
+        bump = question_bank.bump
+    )]
+    pub question_bank: Account<'info, QuestionBank>,
+    
+    #[account(
+        seeds = [b"bank_config"],
+        bump = bank_config.bump
+    )]
+    pub bank_config: Account<'info, BankConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"reputation", question.submitter.as_ref()],
+        bump = submitter_reputation.bump
+    )]
+    pub submitter_reputation: Account<'info, UserReputation>,
+    
+    #[account(
+        mut,
+        seeds = [b"curation_pool"],
+        bump = curation_pool.bump
+    )]
+    pub curation_pool: Account<'info, CurationPool>,
+    
+    /// CHECK: Receives the flag bond refund; must be the flagger
+    #[account(mut, address = flag.flagger)]
+    pub flagger: UncheckedAccount<'info>,
 }
 
 #[derive(Accounts)]
@@ -446,6 +1430,12 @@ pub struct FinalizeQuestion<'info> {
     )]
     pub question_bank: Account<'info, QuestionBank>,
     
+    #[account(
+        seeds = [b"bank_config"],
+        bump = bank_config.bump
+    )]
+    pub bank_config: Account<'info, BankConfig>,
+    
     #[account(
         mut,
         seeds = [b"reputation", question.submitter.as_ref()],
@@ -453,6 +1443,18 @@ pub struct FinalizeQuestion<'info> {
     )]
     pub submitter_reputation: Account<'info, UserReputation>,
     
+    #[account(
+        mut,
+        seeds = [b"curation_pool"],
+        bump = curation_pool.bump
+    )]
+    pub curation_pool: Account<'info, CurationPool>,
+    
+    /// CHECK: Receives the bond refund; must be the question's submitter
+    #[account(mut, address = question.submitter)]
+    pub submitter: UncheckedAccount<'info>,
+    
+    /// Approved curator, or anyone once the voting deadline has passed
     pub curator: Signer<'info>,
 }
 
@@ -464,6 +1466,21 @@ pub struct UpdateReputation<'info> {
         bump = user_reputation.bump
     )]
     pub user_reputation: Account<'info, UserReputation>,
+    
+    #[account(
+        seeds = [b"question_bank"],
+        bump = question_bank.bump
+    )]
+    pub question_bank: Account<'info, QuestionBank>,
+    
+    #[account(
+        seeds = [b"bank_config"],
+        bump = bank_config.bump
+    )]
+    pub bank_config: Account<'info, BankConfig>,
+    
+    /// Bank authority, or the reputation authority PDA of an allowlisted program
+    pub caller: Signer<'info>,
 }
 
 #[derive(Accounts)]
@@ -478,6 +1495,128 @@ pub struct AddCurator<'info> {
     pub authority: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct UpdateBankConfig<'info> {
+    #[account(
+        seeds = [b"question_bank"],
+        bump = question_bank.bump
+    )]
+    pub question_bank: Account<'info, QuestionBank>,
+    
+    #[account(
+        mut,
+        seeds = [b"bank_config"],
+        bump = bank_config.bump
+    )]
+    pub bank_config: Account<'info, BankConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawCurationRewards<'info> {
+    #[account(
+        seeds = [b"question_bank"],
+        bump = question_bank.bump
+    )]
+    pub question_bank: Account<'info, QuestionBank>,
+    
+    #[account(
+        mut,
+        seeds = [b"curation_pool"],
+        bump = curation_pool.bump
+    )]
+    pub curation_pool: Account<'info, CurationPool>,
+    
+    /// CHECK: Any account chosen by the authority to receive rewards
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeStakeVault<'info> {
+    #[account(
+        seeds = [b"question_bank"],
+        bump = question_bank.bump
+    )]
+    pub question_bank: Account<'info, QuestionBank>,
+    
+    #[account(
+        mut,
+        seeds = [b"bank_config"],
+        bump = bank_config.bump
+    )]
+    pub bank_config: Account<'info, BankConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"stake_vault"],
+        bump,
+        token::mint = stake_mint,
+        token::authority = bank_config,
+        token::token_program = token_program
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(mint::token_program = token_program)]
+    pub stake_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimVoteStake<'info> {
+    #[account(
+        seeds = [b"question", question.id.to_le_bytes().as_ref()],
+        bump = question.bump
+    )]
+    pub question: Account<'info, Question>,
+    
+    #[account(
+        seeds = [b"bank_config"],
+        bump = bank_config.bump
+    )]
+    pub bank_config: Account<'info, BankConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"vote", question.key().as_ref(), voter.key().as_ref()],
+        bump = vote_record.bump,
+        has_one = voter
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake_vault"],
+        bump
+    )]
+    pub stake_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(address = bank_config.stake_mint @ QuestionBankError::InvalidStakeMint)]
+    pub stake_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = stake_mint,
+        token::authority = voter,
+        token::token_program = token_program
+    )]
+    pub voter_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub voter: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
 #[derive(Accounts)]
 pub struct RemoveCurator<'info> {
     #[account(
@@ -501,6 +1640,12 @@ pub struct InitializeUserReputation<'info> {
     )]
     pub user_reputation: Account<'info, UserReputation>,
     
+    #[account(
+        seeds = [b"bank_config"],
+        bump = bank_config.bump
+    )]
+    pub bank_config: Account<'info, BankConfig>,
+    
     /// CHECK: This is the user whose reputation is being initialized
     pub user: UncheckedAccount<'info>,
     
@@ -529,11 +1674,152 @@ pub struct QuestionBank {
     pub total_questions: u64,
     pub active_questions: u64,
     pub curators: Vec<Pubkey>,
+    /// Programs allowed to call `update_reputation` via CPI
+    pub reputation_callers: Vec<Pubkey>,
     pub bump: u8,
 }
 
 impl QuestionBank {
-    pub const SPACE: usize = 32 + 8 + 8 + (4 + 32 * 20) + 1; // Support up to 20 curators
+    pub const MAX_REPUTATION_CALLERS: usize = 10;
+    pub const SPACE: usize = 32 + 8 + 8 + (4 + 32 * 20) + (4 + 32 * Self::MAX_REPUTATION_CALLERS) + 1; // Support up to 20 curators
+}
+
+/// Governance-tunable curation thresholds and rewards
+#[account]
+pub struct BankConfig {
+    /// Reputation needed to submit a question
+    pub min_submit_reputation: u64,
+    /// Reputation of a newly initialized user
+    pub initial_reputation: u64,
+    /// Minimum number of votes to finalize a question
+    pub min_votes: u32,
+    /// Seconds a question stays open for voting after submission
+    pub voting_period: i64,
+    /// Minimum combined vote weight to finalize a question
+    pub weighted_quorum: u64,
+    pub vote_weighting: VoteWeighting,
+    /// Reputation needed for each step of tiered weight, ascending
+    pub vote_tiers: [u64; 3],
+    pub submission_reward: u64,
+    pub vote_reward: u64,
+    pub approval_reward: u64,
+    pub rejection_penalty: u64,
+    pub max_question_len: u16,
+    pub max_option_len: u16,
+    pub max_category_len: u16,
+    pub min_difficulty: u8,
+    pub max_difficulty: u8,
+    /// Lamports escrowed per submission, refunded unless rejected
+    pub submission_bond: u64,
+    /// Questions a submitter may have pending at once
+    pub max_pending_questions: u32,
+    /// Lamports escrowed per flag, forfeited if the question is restored
+    pub flag_bond: u64,
+    /// Curator votes needed to resolve a flag
+    pub min_review_votes: u32,
+    /// Token locked per vote; unset until `initialize_stake_vault`
+    pub stake_mint: Pubkey,
+    /// Stake locked per vote, zero disables staking
+    pub vote_stake: u64,
+    /// Share of losing-side stake slashed to winners, in basis points
+    pub stake_slash_bps: u16,
+    pub bump: u8,
+}
+
+impl BankConfig {
+    /// Upper bounds fixed by `Question::SPACE`
+    pub const MAX_QUESTION_LEN: u16 = 500;
+    pub const MAX_OPTION_LEN: u16 = 100;
+    pub const MAX_CATEGORY_LEN: u16 = 50;
+    pub const MAX_DIFFICULTY: u8 = 10;
+    /// Cap on any single reputation reward or penalty
+    pub const MAX_REPUTATION_DELTA: u64 = 1_000;
+    pub const MAX_SUBMISSION_BOND: u64 = 100_000_000_000; // 100 SOL
+    pub const MAX_PENDING_QUESTIONS: u32 = 100;
+    pub const MIN_VOTING_PERIOD: i64 = 60 * 60; // 1 hour
+    pub const MAX_VOTING_PERIOD: i64 = 90 * 24 * 60 * 60; // 90 days
+    /// Voting time left after an edit for stale votes to be re-cast
+    pub const MIN_REVOTE_WINDOW: i64 = 24 * 60 * 60; // 1 day
+    pub const SPACE: usize = 8 + 8 + 4 + 8 + 8 + 1 + 8 * 3 + 8 + 8 + 8 + 8 + 2 + 2 + 2 + 1 + 1 + 8 + 4 + 8 + 4 + 32 + 8 + 2 + 1;
+
+    /// Check every field against its bounds
+    pub fn validate(&self) -> Result<()> {
+        require!(self.min_votes >= 1, QuestionBankError::InvalidConfig);
+        require!(
+            self.vote_tiers.windows(2).all(|pair| pair[0] < pair[1]),
+            QuestionBankError::InvalidConfig
+        );
+        require!(
+            (Self::MIN_VOTING_PERIOD..=Self::MAX_VOTING_PERIOD).contains(&self.voting_period),
+            QuestionBankError::InvalidConfig
+        );
+        require!(
+            self.submission_reward <= Self::MAX_REPUTATION_DELTA
+                && self.vote_reward <= Self::MAX_REPUTATION_DELTA
+                && self.approval_reward <= Self::MAX_REPUTATION_DELTA
+                && self.rejection_penalty <= Self::MAX_REPUTATION_DELTA,
+            QuestionBankError::InvalidConfig
+        );
+        require!(
+            (1..=Self::MAX_QUESTION_LEN).contains(&self.max_question_len)
+                && (1..=Self::MAX_OPTION_LEN).contains(&self.max_option_len)
+                && (1..=Self::MAX_CATEGORY_LEN).contains(&self.max_category_len),
+            QuestionBankError::InvalidConfig
+        );
+        require!(
+            1 <= self.min_difficulty
+                && self.min_difficulty <= self.max_difficulty
+                && self.max_difficulty <= Self::MAX_DIFFICULTY,
+            QuestionBankError::InvalidConfig
+        );
+        require!(
+            self.submission_bond <= Self::MAX_SUBMISSION_BOND
+                && (1..=Self::MAX_PENDING_QUESTIONS).contains(&self.max_pending_questions),
+            QuestionBankError::InvalidConfig
+        );
+        require!(
+            self.flag_bond <= Self::MAX_SUBMISSION_BOND && self.min_review_votes >= 1,
+            QuestionBankError::InvalidConfig
+        );
+        require!(self.stake_slash_bps <= 10_000, QuestionBankError::InvalidConfig);
+        require!(
+            self.vote_stake == 0 || self.stake_mint != Pubkey::default(),
+            QuestionBankError::InvalidConfig
+        );
+        Ok(())
+    }
+}
+
+impl Default for BankConfig {
+    fn default() -> Self {
+        Self {
+            min_submit_reputation: 100,
+            initial_reputation: 100,
+            min_votes: 5,
+            voting_period: 7 * 24 * 60 * 60, // 7 days
+            // Fresh accounts start below the first tier and carry no weight
+            weighted_quorum: 5,
+            vote_weighting: VoteWeighting::Tiered,
+            vote_tiers: [200, 500, 1_000],
+            submission_reward: 5,
+            vote_reward: 10,
+            approval_reward: 50,
+            rejection_penalty: 10,
+            max_question_len: Self::MAX_QUESTION_LEN,
+            max_option_len: Self::MAX_OPTION_LEN,
+            max_category_len: Self::MAX_CATEGORY_LEN,
+            min_difficulty: 1,
+            max_difficulty: 3,
+            submission_bond: 10_000_000, // 0.01 SOL
+            max_pending_questions: 5,
+            flag_bond: 50_000_000, // 0.05 SOL
+            min_review_votes: 3,
+            stake_mint: Pubkey::default(),
+            vote_stake: 0,
+            stake_slash_bps: 5_000,
+            bump: 0,
+        }
+    }
 }
 
 #[account]
@@ -541,20 +1827,82 @@ pub struct Question {
     pub id: u64,
     pub submitter: Pubkey,
     pub question_text: String,
-    pub options: [String; 4],
-    pub correct_answer: u8,
+    /// Answer options, empty for true/false and numeric questions
+    pub options: Vec<String>,
+    /// Question format and its correct answer
+    pub question_type: QuestionType,
     pub category: String,
     pub difficulty: u8,
     pub votes_approve: u32,
     pub votes_reject: u32,
-    pub voters: Vec<Pubkey>,
+    /// Reputation-weighted tallies that decide finalization
+    pub weight_approve: u64,
+    pub weight_reject: u64,
+    /// Total vote stake locked on each side
+    pub stake_approve: u64,
+    pub stake_reject: u64,
+    /// Slash rate snapshotted at finalization
+    pub stake_slash_bps: u16,
     pub status: QuestionStatus,
     pub created_at: i64,
+    /// Votes are rejected after this; anyone can finalize once it passes
+    pub voting_deadline: i64,
+    /// Submission bond escrowed in this account until finalization
+    pub bond: u64,
+    /// Incremented by each `edit_question`
+    pub revision: u32,
+    /// Hash of the content replaced by the latest edit
+    pub previous_revision_hash: [u8; 32],
+    /// Revision that curation votes were settled on
+    pub finalized_revision: u32,
+    /// Number of flags raised, used to derive each `QuestionFlag`
+    pub flag_count: u32,
     pub bump: u8,
 }
 
 impl Question {
-    pub const SPACE: usize = 8 + 32 + 500 + (4 * 100) + 1 + 50 + 1 + 4 + 4 + (4 + 32 * 50) + 1 + 8 + 1; // Support up to 50 voters
+    pub const MIN_OPTIONS: usize = 2;
+    pub const MAX_OPTIONS: usize = 6;
+    pub const SPACE: usize = 8 + 32 + (4 + 500) + (4 + Self::MAX_OPTIONS * (4 + 100)) + QuestionType::SPACE + (4 + 50) + 1 + 4 + 4 + 8 + 8 + 8 + 8 + 2 + 1 + 8 + 8 + 8 + 4 + 32 + 4 + 4 + 1;
+
+    /// Hash of the question's content, as recorded in `previous_revision_hash`
+    pub fn content_hash(&self) -> [u8; 32] {
+        let mut options = Vec::new();
+        for option in &self.options {
+            options.extend_from_slice(&(option.len() as u32).to_le_bytes());
+            options.extend_from_slice(option.as_bytes());
+        }
+        hashv(&[
+            &(self.question_text.len() as u32).to_le_bytes(),
+            self.question_text.as_bytes(),
+            &options,
+            &self.question_type.hash_bytes(),
+            &(self.category.len() as u32).to_le_bytes(),
+            self.category.as_bytes(),
+            &[self.difficulty],
+        ])
+        .to_bytes()
+    }
+}
+
+/// A single curation vote, at `[b"vote", question, voter]`
+#[account]
+pub struct VoteRecord {
+    pub question: Pubkey,
+    pub voter: Pubkey,
+    pub vote_type: VoteType,
+    pub weight: u64,
+    /// Tokens locked with this vote, settled by `claim_vote_stake`
+    pub stake: u64,
+    pub stake_claimed: bool,
+    /// Question revision this vote was cast on
+    pub revision: u32,
+    pub voted_at: i64,
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    pub const SPACE: usize = 32 + 32 + 1 + 8 + 8 + 1 + 8 + 1 + 4;
 }
 
 #[account]
@@ -563,12 +1911,61 @@ pub struct UserReputation {
     pub questions_submitted: u32,
     pub questions_approved: u32,
     pub curation_votes: u32,
+    /// Submitted questions not yet finalized
+    pub pending_questions: u32,
     pub reputation_score: u64,
     pub bump: u8,
 }
 
 impl UserReputation {
-    pub const SPACE: usize = 32 + 4 + 4 + 4 + 8 + 1;
+    pub const SPACE: usize = 32 + 4 + 4 + 4 + 4 + 8 + 1;
+}
+
+/// A bonded report against an approved question, at `[b"flag", question, index]`
+#[account]
+pub struct QuestionFlag {
+    pub question: Pubkey,
+    pub flagger: Pubkey,
+    pub reason: String,
+    /// Corrected answer the `Fix` outcome applies
+    pub proposed_answer: Option<QuestionType>,
+    pub bond: u64,
+    pub votes_restore: u32,
+    pub votes_fix: u32,
+    pub votes_retire: u32,
+    pub outcome: Option<ReviewOutcome>,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl QuestionFlag {
+    pub const MAX_REASON_LEN: usize = 200;
+    pub const SPACE: usize = 32 + 32 + (4 + Self::MAX_REASON_LEN) + (1 + QuestionType::SPACE) + 8 + 4 + 4 + 4 + 2 + 8 + 1;
+}
+
+/// A curator's review vote, at `[b"review_vote", flag, curator]`
+#[account]
+pub struct ReviewVote {
+    pub flag: Pubkey,
+    pub curator: Pubkey,
+    pub outcome: ReviewOutcome,
+    pub bump: u8,
+}
+
+impl ReviewVote {
+    pub const SPACE: usize = 32 + 32 + 1 + 1;
+}
+
+/// Forfeited submission bonds, held as lamports at `[b"curation_pool"]`
+#[account]
+pub struct CurationPool {
+    pub total_forfeited: u64,
+    pub total_withdrawn: u64,
+    pub bump: u8,
+}
+
+impl CurationPool {
+    pub const SPACE: usize = 8 + 8 + 1;
 }
 
 // ============================================================================
@@ -578,17 +1975,90 @@ impl UserReputation {
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct QuestionData {
     pub question_text: String,
-    pub options: [String; 4],
-    pub correct_answer: u8,
+    pub options: Vec<String>,
+    pub question_type: QuestionType,
     pub category: String,
     pub difficulty: u8,
 }
 
+/// Question format, carrying the correct answer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub enum QuestionType {
+    TrueFalse { answer: bool },
+    /// Single correct option out of 2-6
+    MultipleChoice { correct_index: u8 },
+    /// Bit `i` set means option `i` must be chosen
+    MultiSelect { correct_mask: u8 },
+    /// Any answer within `tolerance` of `answer` is correct
+    Numeric { answer: i64, tolerance: u64 },
+}
+
+impl QuestionType {
+    pub const SPACE: usize = 1 + 8 + 8;
+
+    /// Stable encoding used in revision hashes
+    fn hash_bytes(&self) -> Vec<u8> {
+        match self {
+            QuestionType::TrueFalse { answer } => vec![0, *answer as u8],
+            QuestionType::MultipleChoice { correct_index } => vec![1, *correct_index],
+            QuestionType::MultiSelect { correct_mask } => vec![2, *correct_mask],
+            QuestionType::Numeric { answer, tolerance } => {
+                let mut bytes = vec![3];
+                bytes.extend_from_slice(&answer.to_le_bytes());
+                bytes.extend_from_slice(&tolerance.to_le_bytes());
+                bytes
+            }
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct BankConfigUpdate {
+    pub min_submit_reputation: Option<u64>,
+    pub initial_reputation: Option<u64>,
+    pub min_votes: Option<u32>,
+    pub voting_period: Option<i64>,
+    pub weighted_quorum: Option<u64>,
+    pub vote_weighting: Option<VoteWeighting>,
+    pub vote_tiers: Option<[u64; 3]>,
+    pub submission_reward: Option<u64>,
+    pub vote_reward: Option<u64>,
+    pub approval_reward: Option<u64>,
+    pub rejection_penalty: Option<u64>,
+    pub max_question_len: Option<u16>,
+    pub max_option_len: Option<u16>,
+    pub max_category_len: Option<u16>,
+    pub min_difficulty: Option<u8>,
+    pub max_difficulty: Option<u8>,
+    pub submission_bond: Option<u64>,
+    pub max_pending_questions: Option<u32>,
+    pub flag_bond: Option<u64>,
+    pub min_review_votes: Option<u32>,
+    pub vote_stake: Option<u64>,
+    pub stake_slash_bps: Option<u16>,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
 pub enum QuestionStatus {
     Pending,
     Approved,
     Rejected,
+    /// Voting closed without reaching quorum
+    Expired,
+    /// Approved question flagged and awaiting curator review
+    UnderReview,
+    /// Removed from use after review
+    Retired,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub enum ReviewOutcome {
+    /// Keep the question as is
+    Restore,
+    /// Apply the flag's proposed answer
+    Fix,
+    /// Remove the question from use
+    Retire,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -597,6 +2067,16 @@ pub enum VoteType {
     Reject,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub enum VoteWeighting {
+    /// Weight equals reputation score
+    Linear,
+    /// Weight is the square root of reputation score
+    SquareRoot,
+    /// Weight is the number of `vote_tiers` thresholds reached
+    Tiered,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub enum ReputationAction {
     QuestionSubmitted,
@@ -646,4 +2126,70 @@ pub enum QuestionBankError {
     
     #[msg("Cannot remove authority: program authority cannot be removed as curator")]
     CannotRemoveAuthority = 6011,
+    
+    #[msg("Unauthorized reputation caller: only the authority or allowlisted programs can update reputation")]
+    UnauthorizedReputationCaller = 6012,
+    
+    #[msg("Reputation caller already exists: program is already allowlisted")]
+    ReputationCallerAlreadyExists = 6013,
+    
+    #[msg("Reputation caller not found: program is not allowlisted")]
+    ReputationCallerNotFound = 6014,
+    
+    #[msg("Too many reputation callers: allowlist is full")]
+    TooManyReputationCallers = 6015,
+    
+    #[msg("Insufficient vote weight: weighted quorum not reached")]
+    InsufficientVoteWeight = 6016,
+    
+    #[msg("Invalid config: a bank config value is out of bounds")]
+    InvalidConfig = 6017,
+    
+    #[msg("Voting closed: the question's voting deadline has passed")]
+    VotingClosed = 6018,
+    
+    #[msg("Missing stake accounts: vote staking is enabled")]
+    MissingStakeAccounts = 6019,
+    
+    #[msg("Invalid stake mint")]
+    InvalidStakeMint = 6020,
+    
+    #[msg("Question not finalized")]
+    QuestionNotFinalized = 6021,
+    
+    #[msg("Vote has no stake to claim")]
+    NoStake = 6022,
+    
+    #[msg("Vote stake already claimed")]
+    StakeAlreadyClaimed = 6023,
+    
+    #[msg("Too many pending questions: wait for earlier submissions to be finalized")]
+    TooManyPendingQuestions = 6024,
+    
+    #[msg("Insufficient curation pool balance")]
+    InsufficientPoolBalance = 6025,
+    
+    #[msg("Only the question's submitter can edit it")]
+    NotQuestionSubmitter = 6026,
+    
+    #[msg("Vote already cast on the current revision")]
+    VoteNotStale = 6027,
+    
+    #[msg("Question not approved")]
+    QuestionNotApproved = 6028,
+    
+    #[msg("Question not under review")]
+    NotUnderReview = 6029,
+    
+    #[msg("Invalid flag reason: must be 1-200 characters")]
+    InvalidFlagReason = 6030,
+    
+    #[msg("Flag has no proposed fix")]
+    NoProposedFix = 6031,
+    
+    #[msg("Insufficient review votes to resolve the flag")]
+    InsufficientReviewVotes = 6032,
+    
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow = 6033,
 }
\ No newline at end of file
@@ -1,9 +1,20 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer, Mint};
 use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 
 declare_id!("EDy3LJ7eDf8UbpdsikwejxEDPxk48spTG3rwdzuM5TFd");
 
+/// tournament_manager program, the only creator of tournament prize pools
+pub const TOURNAMENT_MANAGER_ID: Pubkey = pubkey!("DE58k65KchHuDCABYARfGP5Jc1p14yRrx1UayweapYx9");
+
+/// Pool IDs with this bit set are reserved for tournament prize pools, so
+/// nobody can claim a tournament's pool ID before it settles
+pub const TOURNAMENT_POOL_ID_FLAG: u64 = 1 << 63;
+
 #[program]
 pub mod reward_distributor {
     use super::*;
@@ -20,14 +31,14 @@ pub mod reward_distributor {
         require!(pool_data.start_time > Clock::get()?.unix_timestamp, RewardDistributorError::InvalidStartTime);
         require!(pool_data.end_time > pool_data.start_time, RewardDistributorError::InvalidEndTime);
         require!(pool_data.total_rewards > 0, RewardDistributorError::InvalidRewardAmount);
+        require!(pool_data.id & TOURNAMENT_POOL_ID_FLAG == 0, RewardDistributorError::ReservedPoolId);
 
         let reward_pool = &mut ctx.accounts.reward_pool;
         
         // Initialize reward pool
         reward_pool.id = pool_data.id;
         reward_pool.authority = ctx.accounts.authority.key();
-        reward_pool.name = pool_data.name;
-        reward_pool.total_rewards = pool_data.total_rewards;
+        reward_pool.name = pool_data.name.clone();
         reward_pool.distributed_rewards = 0;
         reward_pool.reward_type = pool_data.reward_type.clone();
         reward_pool.token_mint = pool_data.token_mint;
@@ -37,33 +48,48 @@ pub mod reward_distributor {
         reward_pool.active = true;
         reward_pool.bump = ctx.bumps.reward_pool;
 
+        let reward_vault = &mut ctx.accounts.reward_vault;
+        reward_vault.pool = reward_pool.key();
+        reward_vault.bump = ctx.bumps.reward_vault;
+
         // Handle initial funding based on reward type
+        let mut funding_received = initial_funding;
         if initial_funding > 0 {
             match &pool_data.reward_type {
                 RewardType::SOL => {
                     // Transfer SOL from authority to vault
                     let cpi_context = CpiContext::new(
                         ctx.accounts.system_program.to_account_info(),
-                        anchor_framework::system_program::Transfer {
+                        anchor_lang::system_program::Transfer {
                             from: ctx.accounts.authority.to_account_info(),
                             to: ctx.accounts.reward_vault.to_account_info(),
                         },
                     );
-                    anchor_framework::system_program::transfer(cpi_context, initial_funding)?;
+                    anchor_lang::system_program::transfer(cpi_context, initial_funding)?;
                 }
                 RewardType::SplToken => {
                     // Transfer SPL tokens from authority to vault
                     require!(pool_data.token_mint.is_some(), RewardDistributorError::MissingTokenMint);
+                    let token_mint = ctx.accounts.token_mint.as_ref().unwrap();
+                    validate_pool_mint(pool_data.token_mint, token_mint)?;
+                    let reward_vault_token = ctx.accounts.reward_vault_token.as_mut().unwrap();
+                    let vault_balance_before = reward_vault_token.amount;
                     
-                    let cpi_accounts = Transfer {
+                    let cpi_accounts = TransferChecked {
                         from: ctx.accounts.authority_token_account.as_ref().unwrap().to_account_info(),
-                        to: ctx.accounts.reward_vault_token.as_ref().unwrap().to_account_info(),
+                        mint: token_mint.to_account_info(),
+                        to: reward_vault_token.to_account_info(),
                         authority: ctx.accounts.authority.to_account_info(),
                     };
                     let cpi_program = ctx.accounts.token_program.as_ref().unwrap().to_account_info();
                     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
                     
-                    token::transfer(cpi_ctx, initial_funding)?;
+                    token_interface::transfer_checked(cpi_ctx, initial_funding, token_mint.decimals)?;
+
+                    reward_vault_token.reload()?;
+                    funding_received = reward_vault_token.amount
+                        .checked_sub(vault_balance_before)
+                        .ok_or(RewardDistributorError::ArithmeticOverflow)?;
                 }
                 RewardType::NFT => {
                     // NFT handling would be implemented here
@@ -73,17 +99,122 @@ pub mod reward_distributor {
             }
         }
 
+        // Transfer fees withheld from the initial funding never reach the
+        // vault, so they cannot be paid out
+        let reward_pool = &mut ctx.accounts.reward_pool;
+        reward_pool.total_rewards = pool_data.total_rewards
+            .checked_sub(initial_funding - funding_received)
+            .ok_or(RewardDistributorError::ArithmeticOverflow)?;
+
         msg!(
             "Reward pool created: ID={}, Name={}, Type={:?}, Total={}",
             pool_data.id,
             pool_data.name,
             pool_data.reward_type,
-            pool_data.total_rewards
+            reward_pool.total_rewards
         );
 
         Ok(())
     }
 
+    /// Create a prize pool for a settled tournament (called via CPI)
+    /// The pool authority is the tournament PDA; eligibility is registered
+    /// per winner with `register_claim` instead of performance data
+    pub fn create_tournament_pool(
+        ctx: Context<CreateTournamentPool>,
+        pool_data: CreateRewardPoolData,
+        initial_funding: u64,
+    ) -> Result<()> {
+        require!(pool_data.name.len() <= 50, RewardDistributorError::InvalidPoolName);
+        require!(pool_data.start_time >= Clock::get()?.unix_timestamp, RewardDistributorError::InvalidStartTime);
+        require!(pool_data.end_time > pool_data.start_time, RewardDistributorError::InvalidEndTime);
+        require!(initial_funding > 0, RewardDistributorError::InvalidRewardAmount);
+
+        // Only the matching tournament PDA may create a reserved pool ID
+        require!(pool_data.id & TOURNAMENT_POOL_ID_FLAG != 0, RewardDistributorError::ReservedPoolId);
+        let tournament_id = pool_data.id & !TOURNAMENT_POOL_ID_FLAG;
+        let (expected_authority, _) = Pubkey::find_program_address(
+            &[b"tournament", tournament_id.to_le_bytes().as_ref()],
+            &TOURNAMENT_MANAGER_ID,
+        );
+        require_keys_eq!(
+            ctx.accounts.authority.key(),
+            expected_authority,
+            RewardDistributorError::UnauthorizedAuthority
+        );
+
+        let token_mint = &ctx.accounts.token_mint;
+        validate_pool_mint(Some(token_mint.key()), token_mint)?;
+
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.authority_token_account.to_account_info(),
+            mint: token_mint.to_account_info(),
+            to: ctx.accounts.reward_vault_token.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+        token_interface::transfer_checked(cpi_ctx, initial_funding, token_mint.decimals)?;
+
+        // Transfer-fee mints deliver less than `initial_funding`
+        ctx.accounts.reward_vault_token.reload()?;
+        let received = ctx.accounts.reward_vault_token.amount;
+
+        let reward_pool = &mut ctx.accounts.reward_pool;
+        reward_pool.id = pool_data.id;
+        reward_pool.authority = ctx.accounts.authority.key();
+        reward_pool.name = pool_data.name;
+        reward_pool.total_rewards = received;
+        reward_pool.distributed_rewards = 0;
+        reward_pool.reward_type = RewardType::SplToken;
+        reward_pool.token_mint = Some(token_mint.key());
+        reward_pool.distribution_criteria = DistributionType::Allocated;
+        reward_pool.start_time = pool_data.start_time;
+        reward_pool.end_time = pool_data.end_time;
+        reward_pool.active = true;
+        reward_pool.bump = ctx.bumps.reward_pool;
+
+        let reward_vault = &mut ctx.accounts.reward_vault;
+        reward_vault.pool = reward_pool.key();
+        reward_vault.bump = ctx.bumps.reward_vault;
+
+        msg!(
+            "Tournament pool created: ID={}, Authority={}, Total={}",
+            reward_pool.id,
+            reward_pool.authority,
+            received
+        );
+
+        Ok(())
+    }
+
+    /// Register a user's reward eligibility directly (pool authority only)
+    /// Used for pools whose payouts are decided up front, such as tournaments
+    pub fn register_claim(
+        ctx: Context<RegisterClaim>,
+        pool_id: u64,
+        user: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        let reward_pool = &ctx.accounts.reward_pool;
+
+        require!(reward_pool.id == pool_id, RewardDistributorError::PoolNotFound);
+        require!(reward_pool.active, RewardDistributorError::PoolNotActive);
+        require!(amount > 0, RewardDistributorError::InvalidRewardAmount);
+
+        let user_claim = &mut ctx.accounts.user_claim;
+        user_claim.pool = reward_pool.key();
+        user_claim.user = user;
+        user_claim.amount_claimed = 0;
+        user_claim.last_claim_time = 0;
+        user_claim.total_eligible = amount;
+        user_claim.bump = ctx.bumps.user_claim;
+
+        msg!("Registered claim of {} for user {} in pool {}", amount, user, pool_id);
+        Ok(())
+    }
+
     /// Fund an existing reward pool with additional rewards
     pub fn fund_reward_pool(
         ctx: Context<FundRewardPool>,
@@ -96,37 +227,53 @@ pub mod reward_distributor {
         require!(reward_pool.active, RewardDistributorError::PoolNotActive);
         require!(amount > 0, RewardDistributorError::InvalidRewardAmount);
 
-        // Handle funding based on reward type
-        match &reward_pool.reward_type {
+        // Handle funding based on reward type. Transfer-fee mints deliver less
+        // than `amount`, so only what the vault actually received is credited.
+        let received = match &reward_pool.reward_type {
             RewardType::SOL => {
                 let cpi_context = CpiContext::new(
                     ctx.accounts.system_program.to_account_info(),
-                    anchor_framework::system_program::Transfer {
+                    anchor_lang::system_program::Transfer {
                         from: ctx.accounts.funder.to_account_info(),
                         to: ctx.accounts.reward_vault.to_account_info(),
                     },
                 );
-                anchor_framework::system_program::transfer(cpi_context, amount)?;
+                anchor_lang::system_program::transfer(cpi_context, amount)?;
+                amount
             }
             RewardType::SplToken => {
-                let cpi_accounts = Transfer {
+                let token_mint = ctx.accounts.token_mint.as_ref().unwrap();
+                validate_pool_mint(reward_pool.token_mint, token_mint)?;
+
+                let reward_vault_token = ctx.accounts.reward_vault_token.as_mut().unwrap();
+                let vault_balance_before = reward_vault_token.amount;
+
+                let cpi_accounts = TransferChecked {
                     from: ctx.accounts.funder_token_account.as_ref().unwrap().to_account_info(),
-                    to: ctx.accounts.reward_vault_token.as_ref().unwrap().to_account_info(),
+                    mint: token_mint.to_account_info(),
+                    to: reward_vault_token.to_account_info(),
                     authority: ctx.accounts.funder.to_account_info(),
                 };
                 let cpi_program = ctx.accounts.token_program.as_ref().unwrap().to_account_info();
                 let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
                 
-                token::transfer(cpi_ctx, amount)?;
+                token_interface::transfer_checked(cpi_ctx, amount, token_mint.decimals)?;
+
+                reward_vault_token.reload()?;
+                reward_vault_token.amount
+                    .checked_sub(vault_balance_before)
+                    .ok_or(RewardDistributorError::ArithmeticOverflow)?
             }
             RewardType::NFT => {
                 return Err(RewardDistributorError::NFTFundingUnsupported.into());
             }
-        }
+        };
 
-        reward_pool.total_rewards += amount;
+        reward_pool.total_rewards = reward_pool.total_rewards
+            .checked_add(received)
+            .ok_or(RewardDistributorError::ArithmeticOverflow)?;
 
-        msg!("Pool {} funded with {} additional rewards", pool_id, amount);
+        msg!("Pool {} funded with {} additional rewards", pool_id, received);
         Ok(())
     }
 
@@ -171,6 +318,10 @@ pub mod reward_distributor {
             DistributionType::RandomDrop => {
                 calculate_random_rewards(reward_pool, &performance_data)?
             }
+            DistributionType::Allocated => {
+                // Eligibility is registered by the pool authority only
+                return Err(RewardDistributorError::AllocatedPool.into());
+            }
         };
 
         // Update user claim record
@@ -226,7 +377,7 @@ pub mod reward_distributor {
             RewardType::SOL => {
                 // Transfer SOL from vault to user
                 let seeds = &[
-                    b"reward_vault",
+                    b"reward_vault".as_ref(),
                     &pool_id.to_le_bytes(),
                     &[ctx.accounts.reward_vault.bump],
                 ];
@@ -234,32 +385,36 @@ pub mod reward_distributor {
 
                 let cpi_context = CpiContext::new_with_signer(
                     ctx.accounts.system_program.to_account_info(),
-                    anchor_framework::system_program::Transfer {
+                    anchor_lang::system_program::Transfer {
                         from: ctx.accounts.reward_vault.to_account_info(),
                         to: ctx.accounts.user.to_account_info(),
                     },
                     signer,
                 );
-                anchor_framework::system_program::transfer(cpi_context, claimable_amount)?;
+                anchor_lang::system_program::transfer(cpi_context, claimable_amount)?;
             }
             RewardType::SplToken => {
                 // Transfer SPL tokens from vault to user
                 let seeds = &[
-                    b"reward_vault",
+                    b"reward_vault".as_ref(),
                     &pool_id.to_le_bytes(),
                     &[ctx.accounts.reward_vault.bump],
                 ];
                 let signer = &[&seeds[..]];
 
-                let cpi_accounts = Transfer {
+                let token_mint = ctx.accounts.token_mint.as_ref().unwrap();
+                validate_pool_mint(reward_pool.token_mint, token_mint)?;
+
+                let cpi_accounts = TransferChecked {
                     from: ctx.accounts.reward_vault_token.as_ref().unwrap().to_account_info(),
+                    mint: token_mint.to_account_info(),
                     to: ctx.accounts.user_token_account.as_ref().unwrap().to_account_info(),
                     authority: ctx.accounts.reward_vault.to_account_info(),
                 };
                 let cpi_program = ctx.accounts.token_program.as_ref().unwrap().to_account_info();
                 let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
                 
-                token::transfer(cpi_ctx, claimable_amount)?;
+                token_interface::transfer_checked(cpi_ctx, claimable_amount, token_mint.decimals)?;
             }
             RewardType::NFT => {
                 // NFT transfer logic would be implemented here
@@ -323,7 +478,7 @@ pub mod reward_distributor {
             match &reward_pool.reward_type {
                 RewardType::SOL => {
                     let seeds = &[
-                        b"reward_vault",
+                        b"reward_vault".as_ref(),
                         &pool_id.to_le_bytes(),
                         &[ctx.accounts.reward_vault.bump],
                     ];
@@ -331,31 +486,35 @@ pub mod reward_distributor {
 
                     let cpi_context = CpiContext::new_with_signer(
                         ctx.accounts.system_program.to_account_info(),
-                        anchor_framework::system_program::Transfer {
+                        anchor_lang::system_program::Transfer {
                             from: ctx.accounts.reward_vault.to_account_info(),
                             to: ctx.accounts.authority.to_account_info(),
                         },
                         signer,
                     );
-                    anchor_framework::system_program::transfer(cpi_context, remaining_funds)?;
+                    anchor_lang::system_program::transfer(cpi_context, remaining_funds)?;
                 }
                 RewardType::SplToken => {
                     let seeds = &[
-                        b"reward_vault",
+                        b"reward_vault".as_ref(),
                         &pool_id.to_le_bytes(),
                         &[ctx.accounts.reward_vault.bump],
                     ];
                     let signer = &[&seeds[..]];
 
-                    let cpi_accounts = Transfer {
+                    let token_mint = ctx.accounts.token_mint.as_ref().unwrap();
+                    validate_pool_mint(reward_pool.token_mint, token_mint)?;
+
+                    let cpi_accounts = TransferChecked {
                         from: ctx.accounts.reward_vault_token.as_ref().unwrap().to_account_info(),
+                        mint: token_mint.to_account_info(),
                         to: ctx.accounts.authority_token_account.as_ref().unwrap().to_account_info(),
                         authority: ctx.accounts.reward_vault.to_account_info(),
                     };
                     let cpi_program = ctx.accounts.token_program.as_ref().unwrap().to_account_info();
                     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
                     
-                    token::transfer(cpi_ctx, remaining_funds)?;
+                    token_interface::transfer_checked(cpi_ctx, remaining_funds, token_mint.decimals)?;
                 }
                 RewardType::NFT => {
                     // NFT return logic would be implemented here
@@ -451,7 +610,7 @@ fn calculate_staking_rewards(
     // Calculate rewards based on staking duration
     let base_reward = reward_pool.total_rewards / 365; // Daily allocation
     
-    let staking_days = performance_data.staking_duration / (24 * 60 * 60); // Convert seconds to days
+    let staking_days = (performance_data.staking_duration / (24 * 60 * 60)) as u64; // Convert seconds to days
     let calculated_reward = base_reward * staking_days;
     
     // Cap at maximum allocation
@@ -480,7 +639,7 @@ fn calculate_random_rewards(
 ) -> Result<u64> {
     // Random drop calculation (simplified)
     let seed = performance_data.random_seed;
-    let random_value = (seed % 100) as u64;
+    let random_value = seed % 100;
     
     if random_value < 10 {
         // 10% chance for rewards
@@ -491,6 +650,51 @@ fn calculate_random_rewards(
     }
 }
 
+/// Mint extensions a reward or tournament vault can hold without extra
+/// accounts or trust assumptions. Transfer hooks, permanent delegates,
+/// non-transferable, default-frozen and confidential mints are rejected.
+pub const SUPPORTED_MINT_EXTENSIONS: [ExtensionType; 8] = [
+    ExtensionType::TransferFeeConfig,
+    ExtensionType::MintCloseAuthority,
+    ExtensionType::InterestBearingConfig,
+    ExtensionType::MetadataPointer,
+    ExtensionType::TokenMetadata,
+    ExtensionType::GroupPointer,
+    ExtensionType::GroupMemberPointer,
+    ExtensionType::TokenGroupMember,
+];
+
+/// Check that `token_mint` is the pool's mint and carries no unsupported
+/// Token-2022 extensions. Legacy SPL Token mints have no extensions.
+fn validate_pool_mint(
+    pool_mint: Option<Pubkey>,
+    token_mint: &InterfaceAccount<Mint>,
+) -> Result<()> {
+    require!(
+        pool_mint == Some(token_mint.key()),
+        RewardDistributorError::InvalidTokenMint
+    );
+
+    require!(
+        mint_extensions_supported(&token_mint.to_account_info())?,
+        RewardDistributorError::UnsupportedMintExtension
+    );
+
+    Ok(())
+}
+
+/// Whether a mint carries only `SUPPORTED_MINT_EXTENSIONS`. Legacy SPL Token
+/// mints have no extensions and always pass. Shared with tournament_manager.
+pub fn mint_extensions_supported(mint_info: &AccountInfo) -> Result<bool> {
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+
+    Ok(mint
+        .get_extension_types()?
+        .iter()
+        .all(|extension| SUPPORTED_MINT_EXTENSIONS.contains(extension)))
+}
+
 fn verify_honeycomb_data(
     achievement_data: &HoneycombAchievementData,
     honeycomb_profile: &AccountInfo,
@@ -531,7 +735,7 @@ pub struct CreateRewardPool<'info> {
         init,
         payer = authority,
         space = 8 + RewardPool::SPACE,
-        seeds = [b"pool", &pool_data.id.to_le_bytes()],
+        seeds = [b"pool", pool_data.id.to_le_bytes().as_ref()],
         bump
     )]
     pub reward_pool: Account<'info, RewardPool>,
@@ -540,7 +744,7 @@ pub struct CreateRewardPool<'info> {
         init,
         payer = authority,
         space = 8 + RewardVault::SPACE,
-        seeds = [b"reward_vault", &pool_data.id.to_le_bytes()],
+        seeds = [b"reward_vault", pool_data.id.to_le_bytes().as_ref()],
         bump
     )]
     pub reward_vault: Account<'info, RewardVault>,
@@ -550,34 +754,112 @@ pub struct CreateRewardPool<'info> {
 
     // Optional token accounts for SPL token rewards
     #[account(mut)]
-    pub authority_token_account: Option<Account<'info, TokenAccount>>,
+    pub authority_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
     
     #[account(
         init_if_needed,
         payer = authority,
         associated_token::mint = token_mint,
-        associated_token::authority = reward_vault
+        associated_token::authority = reward_vault,
+        associated_token::token_program = token_program
     )]
-    pub reward_vault_token: Option<Account<'info, TokenAccount>>,
+    pub reward_vault_token: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_mint: Option<Account<'info, Mint>>,
-    pub token_program: Option<Program<'info, Token>>,
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
     pub associated_token_program: Option<Program<'info, AssociatedToken>>,
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+#[instruction(pool_data: CreateRewardPoolData)]
+pub struct CreateTournamentPool<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + RewardPool::SPACE,
+        seeds = [b"pool", pool_data.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub reward_pool: Box<Account<'info, RewardPool>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + RewardVault::SPACE,
+        seeds = [b"reward_vault", pool_data.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub reward_vault: Box<Account<'info, RewardVault>>,
+
+    /// Tournament PDA signing through tournament_manager
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = authority,
+        token::token_program = token_program
+    )]
+    pub authority_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = token_mint,
+        associated_token::authority = reward_vault,
+        associated_token::token_program = token_program
+    )]
+    pub reward_vault_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(pool_id: u64, user: Pubkey)]
+pub struct RegisterClaim<'info> {
+    #[account(
+        seeds = [b"pool", pool_id.to_le_bytes().as_ref()],
+        bump = reward_pool.bump,
+        has_one = authority
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + UserClaim::SPACE,
+        seeds = [b"claim", pool_id.to_le_bytes().as_ref(), user.as_ref()],
+        bump
+    )]
+    pub user_claim: Account<'info, UserClaim>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 #[instruction(pool_id: u64)]
 pub struct FundRewardPool<'info> {
     #[account(
         mut,
-        seeds = [b"pool", &pool_id.to_le_bytes()],
+        seeds = [b"pool", pool_id.to_le_bytes().as_ref()],
         bump = reward_pool.bump
     )]
     pub reward_pool: Account<'info, RewardPool>,
 
     #[account(
-        seeds = [b"reward_vault", &pool_id.to_le_bytes()],
+        seeds = [b"reward_vault", pool_id.to_le_bytes().as_ref()],
         bump = reward_vault.bump
     )]
     pub reward_vault: Account<'info, RewardVault>,
@@ -587,12 +869,13 @@ pub struct FundRewardPool<'info> {
 
     // Optional token accounts for SPL token funding
     #[account(mut)]
-    pub funder_token_account: Option<Account<'info, TokenAccount>>,
+    pub funder_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
     
     #[account(mut)]
-    pub reward_vault_token: Option<Account<'info, TokenAccount>>,
+    pub reward_vault_token: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Option<Program<'info, Token>>,
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
     pub system_program: Program<'info, System>,
 }
 
@@ -600,7 +883,7 @@ pub struct FundRewardPool<'info> {
 #[instruction(pool_id: u64)]
 pub struct CalculateUserRewards<'info> {
     #[account(
-        seeds = [b"pool", &pool_id.to_le_bytes()],
+        seeds = [b"pool", pool_id.to_le_bytes().as_ref()],
         bump = reward_pool.bump
     )]
     pub reward_pool: Account<'info, RewardPool>,
@@ -609,7 +892,7 @@ pub struct CalculateUserRewards<'info> {
         init_if_needed,
         payer = user,
         space = 8 + UserClaim::SPACE,
-        seeds = [b"claim", &pool_id.to_le_bytes(), user.key().as_ref()],
+        seeds = [b"claim", pool_id.to_le_bytes().as_ref(), user.key().as_ref()],
         bump
     )]
     pub user_claim: Account<'info, UserClaim>,
@@ -628,21 +911,21 @@ pub struct CalculateUserRewards<'info> {
 pub struct ClaimRewards<'info> {
     #[account(
         mut,
-        seeds = [b"pool", &pool_id.to_le_bytes()],
+        seeds = [b"pool", pool_id.to_le_bytes().as_ref()],
         bump = reward_pool.bump
     )]
     pub reward_pool: Account<'info, RewardPool>,
 
     #[account(
         mut,
-        seeds = [b"claim", &pool_id.to_le_bytes(), user.key().as_ref()],
+        seeds = [b"claim", pool_id.to_le_bytes().as_ref(), user.key().as_ref()],
         bump = user_claim.bump
     )]
     pub user_claim: Account<'info, UserClaim>,
 
     #[account(
         mut,
-        seeds = [b"reward_vault", &pool_id.to_le_bytes()],
+        seeds = [b"reward_vault", pool_id.to_le_bytes().as_ref()],
         bump = reward_vault.bump
     )]
     pub reward_vault: Account<'info, RewardVault>,
@@ -652,12 +935,13 @@ pub struct ClaimRewards<'info> {
 
     // Optional token accounts for SPL token claims
     #[account(mut)]
-    pub user_token_account: Option<Account<'info, TokenAccount>>,
+    pub user_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
     
     #[account(mut)]
-    pub reward_vault_token: Option<Account<'info, TokenAccount>>,
+    pub reward_vault_token: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Option<Program<'info, Token>>,
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
     pub system_program: Program<'info, System>,
 }
 
@@ -666,7 +950,7 @@ pub struct ClaimRewards<'info> {
 pub struct UpdateDistributionCriteria<'info> {
     #[account(
         mut,
-        seeds = [b"pool", &pool_id.to_le_bytes()],
+        seeds = [b"pool", pool_id.to_le_bytes().as_ref()],
         bump = reward_pool.bump,
         has_one = authority
     )]
@@ -680,7 +964,7 @@ pub struct UpdateDistributionCriteria<'info> {
 pub struct CloseRewardPool<'info> {
     #[account(
         mut,
-        seeds = [b"pool", &pool_id.to_le_bytes()],
+        seeds = [b"pool", pool_id.to_le_bytes().as_ref()],
         bump = reward_pool.bump,
         has_one = authority
     )]
@@ -688,7 +972,7 @@ pub struct CloseRewardPool<'info> {
 
     #[account(
         mut,
-        seeds = [b"reward_vault", &pool_id.to_le_bytes()],
+        seeds = [b"reward_vault", pool_id.to_le_bytes().as_ref()],
         bump = reward_vault.bump
     )]
     pub reward_vault: Account<'info, RewardVault>,
@@ -698,12 +982,13 @@ pub struct CloseRewardPool<'info> {
 
     // Optional token accounts for returning SPL tokens
     #[account(mut)]
-    pub authority_token_account: Option<Account<'info, TokenAccount>>,
+    pub authority_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
     
     #[account(mut)]
-    pub reward_vault_token: Option<Account<'info, TokenAccount>>,
+    pub reward_vault_token: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Option<Program<'info, Token>>,
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
     pub system_program: Program<'info, System>,
 }
 
@@ -711,7 +996,7 @@ pub struct CloseRewardPool<'info> {
 #[instruction(pool_id: u64)]
 pub struct VerifyHoneycombAchievements<'info> {
     #[account(
-        seeds = [b"pool", &pool_id.to_le_bytes()],
+        seeds = [b"pool", pool_id.to_le_bytes().as_ref()],
         bump = reward_pool.bump
     )]
     pub reward_pool: Account<'info, RewardPool>,
@@ -726,13 +1011,13 @@ pub struct VerifyHoneycombAchievements<'info> {
 #[instruction(pool_id: u64)]
 pub struct GetClaimableAmount<'info> {
     #[account(
-        seeds = [b"pool", &pool_id.to_le_bytes()],
+        seeds = [b"pool", pool_id.to_le_bytes().as_ref()],
         bump = reward_pool.bump
     )]
     pub reward_pool: Account<'info, RewardPool>,
 
     #[account(
-        seeds = [b"claim", &pool_id.to_le_bytes(), user.key().as_ref()],
+        seeds = [b"claim", pool_id.to_le_bytes().as_ref(), user.key().as_ref()],
         bump = user_claim.bump
     )]
     pub user_claim: Account<'info, UserClaim>,
@@ -819,6 +1104,8 @@ pub enum DistributionType {
     StakingRewards,
     AchievementBased,
     RandomDrop,
+    /// Eligibility is registered per user by the pool authority
+    Allocated,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -929,4 +1216,19 @@ pub enum RewardDistributorError {
     
     #[msg("Invalid achievement data")]
     InvalidAchievementData = 6221,
+    
+    #[msg("Token mint does not match the pool mint")]
+    InvalidTokenMint = 6222,
+    
+    #[msg("Token mint uses an extension that reward pools do not support")]
+    UnsupportedMintExtension = 6223,
+    
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow = 6224,
+    
+    #[msg("Pool ID is reserved for tournament prize pools")]
+    ReservedPoolId = 6225,
+    
+    #[msg("Rewards in this pool are allocated by the pool authority")]
+    AllocatedPool = 6226,
 }
\ No newline at end of file
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer, Mint};
+use anchor_lang::solana_program::hash::hashv;
 use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
 
 declare_id!("DE58k65KchHuDCABYARfGP5Jc1p14yRrx1UayweapYx9");
 
@@ -14,6 +17,8 @@ pub mod tournament_manager {
         tournament_manager.authority = ctx.accounts.authority.key();
         tournament_manager.tournament_count = 0;
         tournament_manager.total_participants = 0;
+        tournament_manager.moderators = Vec::new();
+        tournament_manager.banned_players = Vec::new();
         tournament_manager.bump = ctx.bumps.tournament_manager;
         
         msg!("Tournament Manager initialized with authority: {}", tournament_manager.authority);
@@ -21,6 +26,7 @@ pub mod tournament_manager {
     }
 
     /// Create a new tournament
+    #[allow(clippy::too_many_arguments)]
     pub fn create_tournament(
         ctx: Context<CreateTournament>,
         name: String,
@@ -33,13 +39,40 @@ pub mod tournament_manager {
         question_count: u8,
         category: Option<String>,
         difficulty: Option<u8>,
+        crank_bounty: u64,
+        min_rating: Option<u32>,
+        max_rating: Option<u32>,
     ) -> Result<()> {
         require!(name.len() <= 100, TournamentError::NameTooLong);
         require!(description.len() <= 500, TournamentError::DescriptionTooLong);
+        if let Some(category) = &category {
+            require!(category.len() <= 50, TournamentError::CategoryTooLong);
+        }
         require!(max_participants > 0, TournamentError::InvalidMaxParticipants);
         require!(start_time > Clock::get()?.unix_timestamp, TournamentError::InvalidStartTime);
         require!(duration > 0, TournamentError::InvalidDuration);
-        require!(question_count >= 5 && question_count <= 50, TournamentError::InvalidQuestionCount);
+        require!((5..=Tournament::MAX_QUESTIONS as u8).contains(&question_count), TournamentError::InvalidQuestionCount);
+        if let (Some(min), Some(max)) = (min_rating, max_rating) {
+            require!(min <= max, TournamentError::InvalidRatingBracket);
+        }
+        validate_mint_extensions(&ctx.accounts.token_mint.to_account_info())?;
+        require!(
+            ctx.accounts.organizer_profile.bond >= OrganizerProfile::MIN_BOND,
+            TournamentError::InsufficientOrganizerBond
+        );
+
+        // Escrow the crank bounty in the tournament account itself so anyone can
+        // be paid for driving lifecycle transitions if the organizer goes missing
+        if crank_bounty > 0 {
+            let cpi_context = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.organizer.to_account_info(),
+                    to: ctx.accounts.tournament.to_account_info(),
+                },
+            );
+            anchor_lang::system_program::transfer(cpi_context, crank_bounty)?;
+        }
 
         let tournament = &mut ctx.accounts.tournament;
         let tournament_manager = &mut ctx.accounts.tournament_manager;
@@ -57,16 +90,321 @@ pub mod tournament_manager {
         tournament.question_count = question_count;
         tournament.category = category;
         tournament.difficulty = difficulty;
+        tournament.token_mint = ctx.accounts.token_mint.key();
+        tournament.crank_bounty = crank_bounty;
+        tournament.update_count = 0;
+        tournament.min_rating = min_rating;
+        tournament.max_rating = max_rating;
+        tournament.rating_sum = 0;
         tournament.status = TournamentStatus::Registration;
         tournament.created_at = Clock::get()?.unix_timestamp;
         tournament.bump = ctx.bumps.tournament;
 
+        let leaderboard = &mut ctx.accounts.leaderboard;
+        leaderboard.tournament = tournament.key();
+        leaderboard.entries = Vec::new();
+        leaderboard.bump = ctx.bumps.leaderboard;
+
         tournament_manager.tournament_count += 1;
 
+        let organizer_profile = &mut ctx.accounts.organizer_profile;
+        organizer_profile.tournaments_created += 1;
+        organizer_profile.active_tournaments += 1;
+
         msg!("Tournament created: {} by {}", tournament.name, tournament.organizer);
         Ok(())
     }
 
+    /// Edit a tournament before it starts (organizer only)
+    /// Every successful edit bumps `update_count` so participants can see it changed
+    pub fn update_tournament(
+        ctx: Context<UpdateTournament>,
+        update: UpdateTournamentData,
+    ) -> Result<()> {
+        let tournament = &mut ctx.accounts.tournament;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(tournament.status == TournamentStatus::Registration, TournamentError::InvalidStatus);
+        require!(current_time < tournament.start_time, TournamentError::TournamentStarted);
+
+        if let Some(description) = update.description {
+            require!(description.len() <= 500, TournamentError::DescriptionTooLong);
+            tournament.description = description;
+        }
+
+        if let Some(start_time) = update.start_time {
+            // Start time can only move later so registered players are never surprised
+            require!(start_time >= tournament.start_time, TournamentError::InvalidStartTime);
+            tournament.start_time = start_time;
+        }
+
+        if let Some(max_participants) = update.max_participants {
+            require!(
+                max_participants >= tournament.max_participants,
+                TournamentError::InvalidMaxParticipants
+            );
+            tournament.max_participants = max_participants;
+        }
+
+        if let Some(entry_fee) = update.entry_fee {
+            require!(tournament.current_participants == 0, TournamentError::EntryFeeLocked);
+            require!(
+                tournament.sponsor.is_none() || entry_fee == 0,
+                TournamentError::SponsoredTournamentNotFree
+            );
+            tournament.entry_fee = entry_fee;
+        }
+
+        if let Some(category) = update.category {
+            if let Some(category) = &category {
+                require!(category.len() <= 50, TournamentError::CategoryTooLong);
+            }
+            tournament.category = category;
+            tournament.question_ids.clear();
+        }
+
+        if let Some(difficulty) = update.difficulty {
+            tournament.difficulty = difficulty;
+            tournament.question_ids.clear();
+        }
+
+        tournament.update_count += 1;
+
+        msg!("Tournament {} updated (revision {})", tournament.id, tournament.update_count);
+        Ok(())
+    }
+
+    /// Replace the tournament's weighted category/difficulty mix (organizer only)
+    /// Weights are in basis points and must add up to 10_000; an empty mix
+    /// falls back to the single `category` and `difficulty`
+    pub fn set_question_mix(
+        ctx: Context<UpdateTournament>,
+        question_mix: Vec<QuestionMixEntry>,
+    ) -> Result<()> {
+        let tournament = &mut ctx.accounts.tournament;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(tournament.status == TournamentStatus::Registration, TournamentError::InvalidStatus);
+        require!(current_time < tournament.start_time, TournamentError::TournamentStarted);
+        require!(
+            question_mix.len() <= Tournament::MAX_MIX_ENTRIES,
+            TournamentError::InvalidQuestionMix
+        );
+        if !question_mix.is_empty() {
+            let total_weight: u32 = question_mix.iter().map(|entry| entry.weight_bps as u32).sum();
+            require!(total_weight == 10_000, TournamentError::InvalidQuestionMix);
+        }
+        for entry in question_mix.iter() {
+            require!(entry.weight_bps > 0, TournamentError::InvalidQuestionMix);
+            if let Some(category) = &entry.category {
+                require!(category.len() <= QuestionMixEntry::MAX_CATEGORY_LEN, TournamentError::InvalidQuestionMix);
+            }
+        }
+
+        tournament.question_mix = question_mix;
+        tournament.question_ids.clear();
+        tournament.update_count += 1;
+
+        msg!(
+            "Tournament {} question mix set to {} buckets (revision {})",
+            tournament.id,
+            tournament.question_mix.len(),
+            tournament.update_count
+        );
+        Ok(())
+    }
+
+    /// Select the tournament's questions from the question bank (organizer only)
+    /// Pass `question_count` question accounts as remaining accounts, grouped
+    /// by mix bucket in order. Each bucket receives its weighted share of the
+    /// questions, with rounding leftovers going to the largest remainders
+    pub fn select_questions<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateTournament<'info>>,
+    ) -> Result<()> {
+        let tournament = &mut ctx.accounts.tournament;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(tournament.status == TournamentStatus::Registration, TournamentError::InvalidStatus);
+        require!(current_time < tournament.start_time, TournamentError::TournamentStarted);
+
+        let buckets = if tournament.question_mix.is_empty() {
+            vec![QuestionMixEntry {
+                category: tournament.category.clone(),
+                difficulty: tournament.difficulty,
+                weight_bps: 10_000,
+            }]
+        } else {
+            tournament.question_mix.clone()
+        };
+        let bucket_counts = mix_bucket_counts(&buckets, tournament.question_count);
+
+        let mut questions = ctx.remaining_accounts.iter();
+        let mut question_ids: Vec<u64> = Vec::with_capacity(tournament.question_count as usize);
+        for (bucket_index, (bucket, count)) in buckets.iter().zip(bucket_counts.iter()).enumerate() {
+            for _ in 0..*count {
+                let question_info = match questions.next() {
+                    Some(info) => info,
+                    None => {
+                        msg!("Question bank cannot fill bucket {}", bucket_index);
+                        return err!(TournamentError::InsufficientQuestionsForBucket);
+                    }
+                };
+                let question = Account::<question_bank::Question>::try_from(question_info)?;
+
+                if !question_matches(&question, &bucket.category, bucket.difficulty) {
+                    msg!("Question {} does not fit bucket {}", question.id, bucket_index);
+                    return err!(TournamentError::InsufficientQuestionsForBucket);
+                }
+                require!(!question_ids.contains(&question.id), TournamentError::DuplicateQuestion);
+
+                question_ids.push(question.id);
+            }
+        }
+        require!(questions.next().is_none(), TournamentError::InvalidQuestionCount);
+
+        tournament.question_ids = question_ids;
+
+        msg!("{} questions selected for tournament {}", tournament.question_ids.len(), tournament.id);
+        Ok(())
+    }
+
+    /// Sponsor a free tournament by escrowing its prize pool (organizer and sponsor sign)
+    /// Sponsored tournaments stay free to enter and carry the sponsor's branding
+    pub fn sponsor_tournament(
+        ctx: Context<SponsorTournament>,
+        sponsorship: SponsorshipData,
+    ) -> Result<()> {
+        let tournament = &ctx.accounts.tournament;
+
+        require!(tournament.status == TournamentStatus::Registration, TournamentError::InvalidStatus);
+        require!(tournament.entry_fee == 0, TournamentError::SponsoredTournamentNotFree);
+        require!(tournament.sponsor.is_none(), TournamentError::AlreadySponsored);
+        require!(sponsorship.name.len() <= SponsorInfo::MAX_NAME_LEN, TournamentError::NameTooLong);
+        require!(sponsorship.uri.len() <= SponsorInfo::MAX_URI_LEN, TournamentError::InvalidSponsorUri);
+        require!(sponsorship.prize_amount > 0, TournamentError::InvalidPrizeData);
+
+        // Credit what the vault actually received, as for entry fees
+        let vault_balance_before = ctx.accounts.tournament_vault.amount;
+
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.sponsor_token_account.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
+            to: ctx.accounts.tournament_vault.to_account_info(),
+            authority: ctx.accounts.sponsor.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+        token_interface::transfer_checked(cpi_ctx, sponsorship.prize_amount, ctx.accounts.token_mint.decimals)?;
+
+        ctx.accounts.tournament_vault.reload()?;
+        let funded_amount = ctx.accounts.tournament_vault.amount
+            .checked_sub(vault_balance_before)
+            .ok_or(TournamentError::ArithmeticOverflow)?;
+
+        let tournament = &mut ctx.accounts.tournament;
+        tournament.prize_pool = tournament.prize_pool
+            .checked_add(funded_amount)
+            .ok_or(TournamentError::ArithmeticOverflow)?;
+        tournament.sponsor = Some(SponsorInfo {
+            sponsor: ctx.accounts.sponsor.key(),
+            name: sponsorship.name,
+            uri: sponsorship.uri,
+            logo_hash: sponsorship.logo_hash,
+            required_mint: sponsorship.required_mint,
+            required_amount: sponsorship.required_amount,
+            funded_amount,
+            reclaimed: false,
+        });
+
+        msg!(
+            "Tournament {} sponsored by {} with {} prize funds",
+            tournament.id,
+            ctx.accounts.sponsor.key(),
+            funded_amount
+        );
+        Ok(())
+    }
+
+    /// Return a sponsor's unused funds (sponsor only)
+    /// For settled tournaments this waits for the prize claim deadline and
+    /// pulls unclaimed prizes back from the reward_distributor pool first
+    pub fn reclaim_sponsor_funds(ctx: Context<ReclaimSponsorFunds>) -> Result<()> {
+        let tournament = &ctx.accounts.tournament;
+        let sponsor = tournament.sponsor.as_ref().ok_or(TournamentError::NotSponsored)?;
+
+        require_keys_eq!(sponsor.sponsor, ctx.accounts.sponsor.key(), TournamentError::UnauthorizedSponsor);
+        require!(!sponsor.reclaimed, TournamentError::SponsorFundsReclaimed);
+        require!(
+            matches!(tournament.status, TournamentStatus::Settled | TournamentStatus::Cancelled),
+            TournamentError::InvalidStatus
+        );
+
+        let tournament_id = tournament.id.to_le_bytes();
+        let seeds = &[
+            b"tournament".as_ref(),
+            tournament_id.as_ref(),
+            &[tournament.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        if tournament.status == TournamentStatus::Settled && tournament.prizes_distributed > 0 {
+            let (Some(reward_pool), Some(reward_vault), Some(reward_vault_token), Some(reward_distributor_program)) = (
+                ctx.accounts.reward_pool.as_ref(),
+                ctx.accounts.reward_vault.as_ref(),
+                ctx.accounts.reward_vault_token.as_ref(),
+                ctx.accounts.reward_distributor_program.as_ref(),
+            ) else {
+                return err!(TournamentError::MissingPrizePoolAccounts);
+            };
+
+            let cpi_accounts = reward_distributor::cpi::accounts::CloseRewardPool {
+                reward_pool: reward_pool.to_account_info(),
+                reward_vault: reward_vault.to_account_info(),
+                authority: tournament.to_account_info(),
+                authority_token_account: Some(ctx.accounts.tournament_vault.to_account_info()),
+                reward_vault_token: Some(reward_vault_token.to_account_info()),
+                token_mint: Some(ctx.accounts.token_mint.to_account_info()),
+                token_program: Some(ctx.accounts.token_program.to_account_info()),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            };
+            let cpi_program = reward_distributor_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+            // Fails until the pool's claim period has ended
+            reward_distributor::cpi::close_reward_pool(
+                cpi_ctx,
+                reward_distributor::TOURNAMENT_POOL_ID_FLAG | tournament.id,
+            )?;
+
+            ctx.accounts.tournament_vault.reload()?;
+        }
+
+        // Free tournaments hold nothing but sponsor funds
+        let reclaimed = ctx.accounts.tournament_vault.amount;
+        if reclaimed > 0 {
+            let cpi_accounts = TransferChecked {
+                from: ctx.accounts.tournament_vault.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.sponsor_token_account.to_account_info(),
+                authority: tournament.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+            token_interface::transfer_checked(cpi_ctx, reclaimed, ctx.accounts.token_mint.decimals)?;
+        }
+
+        let tournament = &mut ctx.accounts.tournament;
+        tournament.prize_pool = tournament.prize_pool.saturating_sub(reclaimed);
+        if let Some(sponsor) = tournament.sponsor.as_mut() {
+            sponsor.reclaimed = true;
+        }
+
+        msg!("Sponsor reclaimed {} from tournament {}", reclaimed, tournament.id);
+        Ok(())
+    }
+
     /// Register for a tournament
     pub fn register_for_tournament(
         ctx: Context<RegisterForTournament>,
@@ -77,18 +415,46 @@ pub mod tournament_manager {
         require!(tournament.status == TournamentStatus::Registration, TournamentError::RegistrationClosed);
         require!(tournament.current_participants < tournament.max_participants, TournamentError::TournamentFull);
         require!(Clock::get()?.unix_timestamp < tournament.start_time, TournamentError::TournamentStarted);
+        require!(
+            !ctx.accounts.tournament_manager.banned_players.contains(&ctx.accounts.participant.key()),
+            TournamentError::PlayerBanned
+        );
+
+        // Sponsors may restrict entry to holders of their token
+        if let Some(SponsorInfo { required_mint: Some(required_mint), required_amount, .. }) = &tournament.sponsor {
+            let sponsor_token_account = ctx.accounts.sponsor_token_account
+                .as_ref()
+                .ok_or(TournamentError::SponsorRequirementNotMet)?;
+            require!(
+                sponsor_token_account.mint == *required_mint
+                    && sponsor_token_account.owner == ctx.accounts.participant.key()
+                    && sponsor_token_account.amount >= *required_amount,
+                TournamentError::SponsorRequirementNotMet
+            );
+        }
 
-        // Handle entry fee payment if required
+        // Handle entry fee payment if required. Transfer-fee mints deliver less
+        // than `entry_fee`, so the prize pool is credited with what the vault
+        // actually received.
+        let mut fee_received = 0;
         if tournament.entry_fee > 0 {
-            let cpi_accounts = Transfer {
+            let vault_balance_before = ctx.accounts.tournament_vault.amount;
+
+            let cpi_accounts = TransferChecked {
                 from: ctx.accounts.participant_token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
                 to: ctx.accounts.tournament_vault.to_account_info(),
                 authority: ctx.accounts.participant.to_account_info(),
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-            
-            token::transfer(cpi_ctx, tournament.entry_fee)?;
+
+            token_interface::transfer_checked(cpi_ctx, tournament.entry_fee, ctx.accounts.token_mint.decimals)?;
+
+            ctx.accounts.tournament_vault.reload()?;
+            fee_received = ctx.accounts.tournament_vault.amount
+                .checked_sub(vault_balance_before)
+                .ok_or(TournamentError::ArithmeticOverflow)?;
         }
 
         registration.participant = ctx.accounts.participant.key();
@@ -96,84 +462,336 @@ pub mod tournament_manager {
         registration.registered_at = Clock::get()?.unix_timestamp;
         registration.score = 0;
         registration.completed = false;
+        registration.entry_fee_paid = fee_received;
+        registration.correct_answers = 0;
+        registration.scored_voided_questions = 0;
+        registration.disqualified = false;
+        registration.disqualification_reason = 0;
+        registration.answers = Vec::new();
+        registration.saved_questions = 0;
+        registration.last_saved_at = None;
+        registration.refunded = false;
         registration.bump = ctx.bumps.registration;
 
         tournament.current_participants += 1;
-        tournament.prize_pool += tournament.entry_fee;
+        tournament.open_registrations += 1;
+        ctx.accounts.tournament_manager.total_participants += 1;
+
+        // Player profiles are created lazily on first registration
+        let player_profile = &mut ctx.accounts.player_profile;
+        if player_profile.player == Pubkey::default() {
+            player_profile.player = ctx.accounts.participant.key();
+            player_profile.rating = PlayerProfile::INITIAL_RATING;
+            player_profile.bump = ctx.bumps.player_profile;
+        }
+        player_profile.tournaments_entered += 1;
+
+        // Enforce the tournament's skill bracket
+        if let Some(min_rating) = tournament.min_rating {
+            require!(player_profile.rating >= min_rating, TournamentError::RatingOutOfBracket);
+        }
+        if let Some(max_rating) = tournament.max_rating {
+            require!(player_profile.rating <= max_rating, TournamentError::RatingOutOfBracket);
+        }
+        registration.rating_at_registration = player_profile.rating;
+        registration.rating_applied = false;
+        tournament.rating_sum += player_profile.rating as u64;
+
+        tournament.prize_pool = tournament.prize_pool
+            .checked_add(fee_received)
+            .ok_or(TournamentError::ArithmeticOverflow)?;
 
         msg!("Participant {} registered for tournament {}", registration.participant, tournament.id);
         Ok(())
     }
 
     /// Start a tournament
+    /// The organizer may start before `start_time`; the crank has to wait
     pub fn start_tournament(ctx: Context<StartTournament>) -> Result<()> {
         let tournament = &mut ctx.accounts.tournament;
         
-        require!(tournament.status == TournamentStatus::Registration, TournamentError::InvalidStatus);
-        require!(Clock::get()?.unix_timestamp >= tournament.start_time, TournamentError::TournamentNotReady);
-        require!(tournament.current_participants >= 2, TournamentError::InsufficientParticipants);
-
-        tournament.status = TournamentStatus::Active;
-        tournament.actual_start_time = Some(Clock::get()?.unix_timestamp);
+        activate_tournament(tournament, Clock::get()?.unix_timestamp, true)?;
 
         msg!("Tournament {} started with {} participants", tournament.id, tournament.current_participants);
         Ok(())
     }
 
+    /// Start a tournament once `start_time` has passed (permissionless)
+    /// Pays the caller half of the remaining crank bounty
+    pub fn crank_start_tournament(ctx: Context<CrankTournament>) -> Result<()> {
+        let tournament = &mut ctx.accounts.tournament;
+
+        activate_tournament(tournament, Clock::get()?.unix_timestamp, false)?;
+
+        // Leave the other half of the bounty for the end transition
+        let reward = tournament.crank_bounty / 2;
+        pay_crank_reward(tournament, &ctx.accounts.cranker.to_account_info(), reward)?;
+
+        msg!(
+            "Tournament {} started by crank {} (reward: {})",
+            tournament.id,
+            ctx.accounts.cranker.key(),
+            reward
+        );
+        Ok(())
+    }
+
     /// Submit answers for a tournament
-    pub fn submit_answers(
-        ctx: Context<SubmitAnswers>,
-        answers: Vec<u8>,
+    /// Pass the tournament's question accounts as remaining accounts, in
+    /// `question_ids` order, so each answer can be graded
+    pub fn submit_answers<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SubmitAnswers<'info>>,
+        answers: Vec<i64>,
     ) -> Result<()> {
-        let tournament = &ctx.accounts.tournament;
+        let current_time = Clock::get()?.unix_timestamp;
+        authorize_gameplay(
+            &ctx.accounts.registration,
+            &ctx.accounts.signer.key(),
+            ctx.accounts.game_session.as_ref(),
+            current_time,
+        )?;
+
+        let tournament = &mut ctx.accounts.tournament;
         let registration = &mut ctx.accounts.registration;
         
         require!(tournament.status == TournamentStatus::Active, TournamentError::TournamentNotActive);
         require!(!registration.completed, TournamentError::AlreadySubmitted);
+        require!(!registration.disqualified, TournamentError::ParticipantDisqualified);
         require!(answers.len() == tournament.question_count as usize, TournamentError::InvalidAnswerCount);
 
-        let current_time = Clock::get()?.unix_timestamp;
         let tournament_end_time = tournament.actual_start_time.unwrap() + tournament.duration;
         require!(current_time <= tournament_end_time, TournamentError::TournamentEnded);
 
-        // Calculate score (simplified scoring)
-        let mut score = 0;
-        for (i, answer) in answers.iter().enumerate() {
-            // In a real implementation, this would check against correct answers
-            // For now, assume 70% correct rate
-            if i % 10 < 7 {
-                score += 10;
-            }
+        // Replaces anything saved earlier
+        if registration.saved_questions != 0 {
+            tournament.pending_finalizations -= 1;
         }
+        registration.saved_questions = question_mask(tournament.question_count);
+        registration.answers = answers;
+        registration.last_saved_at = Some(current_time);
 
-        registration.score = score;
-        registration.completed = true;
-        registration.submission_time = Some(current_time);
+        finalize_registration(
+            tournament,
+            registration,
+            &mut ctx.accounts.player_profile,
+            &mut ctx.accounts.leaderboard,
+            ctx.remaining_accounts,
+            current_time,
+        )?;
 
-        msg!("Answers submitted by {} with score: {}", registration.participant, score);
+        msg!("Answers submitted by {} with score: {}", registration.participant, registration.score);
         Ok(())
     }
 
-    /// End a tournament and calculate winners
-    pub fn end_tournament(ctx: Context<EndTournament>) -> Result<()> {
+    /// Save a chunk of answers starting at `start_index`
+    /// Saved answers can be overwritten until they are finalized
+    pub fn save_answers(
+        ctx: Context<SaveAnswers>,
+        start_index: u8,
+        answers: Vec<i64>,
+    ) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        authorize_gameplay(
+            &ctx.accounts.registration,
+            &ctx.accounts.signer.key(),
+            ctx.accounts.game_session.as_ref(),
+            current_time,
+        )?;
+
         let tournament = &mut ctx.accounts.tournament;
-        
+        let registration = &mut ctx.accounts.registration;
+
         require!(tournament.status == TournamentStatus::Active, TournamentError::TournamentNotActive);
-        
+        require!(!registration.completed, TournamentError::AlreadySubmitted);
+        require!(!registration.disqualified, TournamentError::ParticipantDisqualified);
+        require!(
+            !answers.is_empty()
+                && start_index as usize + answers.len() <= tournament.question_count as usize,
+            TournamentError::InvalidAnswerCount
+        );
+
+        let tournament_end_time = tournament.actual_start_time.unwrap() + tournament.duration;
+        require!(current_time <= tournament_end_time, TournamentError::TournamentEnded);
+
+        if registration.saved_questions == 0 {
+            registration.answers = vec![0; tournament.question_count as usize];
+            tournament.pending_finalizations += 1;
+        }
+
+        let start = start_index as usize;
+        registration.answers[start..start + answers.len()].copy_from_slice(&answers);
+        registration.saved_questions |= question_mask(answers.len() as u8) << start_index;
+        registration.last_saved_at = Some(current_time);
+
+        msg!(
+            "{} answers saved by {} from question {} ({} saved)",
+            answers.len(),
+            registration.participant,
+            start_index,
+            registration.saved_questions.count_ones()
+        );
+        Ok(())
+    }
+
+    /// Finalize previously saved answers; unanswered questions score zero
+    /// Takes the same question accounts as `submit_answers`
+    pub fn finalize_answers<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SubmitAnswers<'info>>,
+    ) -> Result<()> {
         let current_time = Clock::get()?.unix_timestamp;
+        authorize_gameplay(
+            &ctx.accounts.registration,
+            &ctx.accounts.signer.key(),
+            ctx.accounts.game_session.as_ref(),
+            current_time,
+        )?;
+
+        let tournament = &mut ctx.accounts.tournament;
+        let registration = &mut ctx.accounts.registration;
+
+        require!(tournament.status == TournamentStatus::Active, TournamentError::TournamentNotActive);
+        require!(!registration.completed, TournamentError::AlreadySubmitted);
+        require!(!registration.disqualified, TournamentError::ParticipantDisqualified);
+        require!(registration.saved_questions != 0, TournamentError::NoAnswersSaved);
+
         let tournament_end_time = tournament.actual_start_time.unwrap() + tournament.duration;
-        require!(current_time >= tournament_end_time, TournamentError::TournamentNotEnded);
+        require!(current_time <= tournament_end_time, TournamentError::TournamentEnded);
+
+        tournament.pending_finalizations -= 1;
+        finalize_registration(
+            tournament,
+            registration,
+            &mut ctx.accounts.player_profile,
+            &mut ctx.accounts.leaderboard,
+            ctx.remaining_accounts,
+            current_time,
+        )?;
+
+        msg!("Answers finalized by {} with score: {}", registration.participant, registration.score);
+        Ok(())
+    }
+
+    /// Finalize the saved answers of a registration after the tournament
+    /// ended (permissionless). The last save time is used as submission time
+    /// Takes the same question accounts as `submit_answers`
+    pub fn crank_finalize_answers<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CrankFinalizeAnswers<'info>>,
+    ) -> Result<()> {
+        let tournament = &mut ctx.accounts.tournament;
+        let registration = &mut ctx.accounts.registration;
+
+        require!(tournament.status == TournamentStatus::Ended, TournamentError::TournamentNotEnded);
+        require!(!registration.completed, TournamentError::AlreadySubmitted);
+        require!(!registration.disqualified, TournamentError::ParticipantDisqualified);
+        require!(registration.saved_questions != 0, TournamentError::NoAnswersSaved);
 
-        tournament.status = TournamentStatus::Ended;
-        tournament.ended_at = Some(current_time);
+        tournament.pending_finalizations -= 1;
+        let submission_time = registration.last_saved_at.unwrap();
+        finalize_registration(
+            tournament,
+            registration,
+            &mut ctx.accounts.player_profile,
+            &mut ctx.accounts.leaderboard,
+            ctx.remaining_accounts,
+            submission_time,
+        )?;
+
+        msg!(
+            "Saved answers of {} finalized with score: {} ({} remaining)",
+            registration.participant,
+            registration.score,
+            tournament.pending_finalizations
+        );
+        Ok(())
+    }
+
+    /// Authorize an ephemeral key to play on behalf of a registration
+    /// The session only covers gameplay instructions and never moves funds
+    pub fn create_game_session(
+        ctx: Context<CreateGameSession>,
+        session_key: Pubkey,
+        duration: i64,
+    ) -> Result<()> {
+        require!(
+            duration > 0 && duration <= GameSession::MAX_DURATION,
+            TournamentError::InvalidSessionDuration
+        );
+        require!(
+            matches!(
+                ctx.accounts.tournament.status,
+                TournamentStatus::Registration | TournamentStatus::Active
+            ),
+            TournamentError::TournamentNotActive
+        );
+        require!(!ctx.accounts.registration.completed, TournamentError::AlreadySubmitted);
+        require!(!ctx.accounts.registration.disqualified, TournamentError::ParticipantDisqualified);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let game_session = &mut ctx.accounts.game_session;
+        game_session.participant = ctx.accounts.participant.key();
+        game_session.registration = ctx.accounts.registration.key();
+        game_session.session_key = session_key;
+        game_session.created_at = current_time;
+        game_session.expires_at = current_time + duration;
+        game_session.bump = ctx.bumps.game_session;
+
+        msg!(
+            "Session key {} authorized for {} until {}",
+            session_key,
+            game_session.participant,
+            game_session.expires_at
+        );
+        Ok(())
+    }
+
+    /// Revoke a session key, returning the session rent to the participant
+    pub fn revoke_game_session(ctx: Context<RevokeGameSession>) -> Result<()> {
+        msg!(
+            "Session key {} revoked by {}",
+            ctx.accounts.game_session.session_key,
+            ctx.accounts.participant.key()
+        );
+        Ok(())
+    }
+
+    /// End a tournament and calculate winners
+    /// The organizer may end it before its duration has elapsed
+    pub fn end_tournament(ctx: Context<EndTournament>) -> Result<()> {
+        let tournament = &mut ctx.accounts.tournament;
+        
+        conclude_tournament(tournament, Clock::get()?.unix_timestamp, true)?;
 
         msg!("Tournament {} ended", tournament.id);
         Ok(())
     }
 
+    /// End a tournament once `actual_start_time + duration` has passed (permissionless)
+    /// Pays the caller whatever remains of the crank bounty
+    pub fn crank_end_tournament(ctx: Context<CrankTournament>) -> Result<()> {
+        let tournament = &mut ctx.accounts.tournament;
+
+        conclude_tournament(tournament, Clock::get()?.unix_timestamp, false)?;
+
+        let reward = tournament.crank_bounty;
+        pay_crank_reward(tournament, &ctx.accounts.cranker.to_account_info(), reward)?;
+
+        msg!(
+            "Tournament {} ended by crank {} (reward: {})",
+            tournament.id,
+            ctx.accounts.cranker.key(),
+            reward
+        );
+        Ok(())
+    }
+
     /// Distribute prizes to winners
-    pub fn distribute_prizes(
-        ctx: Context<DistributePrizes>,
+    /// Winners must be the top of the leaderboard, best first. The prizes move
+    /// from the tournament vault into a reward_distributor pool where each
+    /// winner claims through `claim_rewards`. For every winner, pass their
+    /// player profile and (uninitialized) reward claim account as writable
+    /// remaining accounts, in that order
+    pub fn distribute_prizes<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributePrizes<'info>>,
         winners: Vec<Pubkey>,
         prize_amounts: Vec<u64>,
     ) -> Result<()> {
@@ -181,92 +799,2152 @@ pub mod tournament_manager {
         
         require!(tournament.status == TournamentStatus::Ended, TournamentError::TournamentNotEnded);
         require!(winners.len() == prize_amounts.len(), TournamentError::InvalidPrizeData);
+
+        // Payouts stay locked until the dispute window has closed, every
+        // dispute is resolved and all registrations reflect voided questions
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time >= tournament.ended_at.unwrap() + Dispute::WINDOW_SECONDS,
+            TournamentError::DisputeWindowOpen
+        );
+        require!(tournament.open_disputes == 0, TournamentError::UnresolvedDisputes);
+        require!(tournament.pending_rescores == 0, TournamentError::RescorePending);
+        require!(tournament.pending_finalizations == 0, TournamentError::FinalizationPending);
         
-        let total_prizes: u64 = prize_amounts.iter().sum();
+        let total_prizes = prize_amounts
+            .iter()
+            .try_fold(0u64, |total, amount| total.checked_add(*amount))
+            .ok_or(TournamentError::ArithmeticOverflow)?;
         require!(total_prizes <= tournament.prize_pool, TournamentError::InsufficientPrizePool);
 
-        // Prize distribution logic would go here
-        // This would involve multiple token transfers to winners
+        // Winners come straight from the leaderboard
+        let leaderboard = &ctx.accounts.leaderboard;
+        require!(winners.len() <= leaderboard.entries.len(), TournamentError::InvalidPrizeData);
+        for (winner, entry) in winners.iter().zip(leaderboard.entries.iter()) {
+            require_keys_eq!(*winner, entry.participant, TournamentError::WinnerNotOnLeaderboard);
+        }
+        require!(ctx.remaining_accounts.len() == winners.len() * 2, TournamentError::InvalidPrizeData);
+
+        let tournament_id = tournament.id.to_le_bytes();
+        let seeds = &[
+            b"tournament".as_ref(),
+            tournament_id.as_ref(),
+            &[tournament.bump],
+        ];
+        let signer = &[&seeds[..]];
+        let pool_id = reward_distributor::TOURNAMENT_POOL_ID_FLAG | tournament.id;
+
+        if total_prizes > 0 {
+            let pool_data = reward_distributor::CreateRewardPoolData {
+                id: pool_id,
+                name: format!("Tournament {} prizes", tournament.id),
+                total_rewards: total_prizes,
+                reward_type: reward_distributor::RewardType::SplToken,
+                token_mint: Some(tournament.token_mint),
+                distribution_criteria: reward_distributor::DistributionType::Allocated,
+                start_time: current_time,
+                end_time: current_time + PRIZE_CLAIM_PERIOD,
+            };
+
+            let cpi_accounts = reward_distributor::cpi::accounts::CreateTournamentPool {
+                reward_pool: ctx.accounts.reward_pool.to_account_info(),
+                reward_vault: ctx.accounts.reward_vault.to_account_info(),
+                authority: tournament.to_account_info(),
+                payer: ctx.accounts.organizer.to_account_info(),
+                authority_token_account: ctx.accounts.tournament_vault.to_account_info(),
+                reward_vault_token: ctx.accounts.reward_vault_token.to_account_info(),
+                token_mint: ctx.accounts.token_mint.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.reward_distributor_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+            reward_distributor::cpi::create_tournament_pool(cpi_ctx, pool_data, total_prizes)?;
+        }
+
+        // Transfer-fee mints leave the pool with less than `total_prizes`,
+        // so every prize is scaled down by the same ratio
+        let pool_funds = if total_prizes > 0 {
+            let data = ctx.accounts.reward_vault_token.try_borrow_data()?;
+            TokenAccount::try_deserialize(&mut &data[..])?.amount
+        } else {
+            0
+        };
+
+        for (rank, ((winner, amount), accounts)) in winners
+            .iter()
+            .zip(prize_amounts.iter())
+            .zip(ctx.remaining_accounts.chunks(2))
+            .enumerate()
+        {
+            let prize = if total_prizes > 0 {
+                (*amount as u128 * pool_funds as u128 / total_prizes as u128) as u64
+            } else {
+                0
+            };
+
+            if prize > 0 {
+                let cpi_accounts = reward_distributor::cpi::accounts::RegisterClaim {
+                    reward_pool: ctx.accounts.reward_pool.to_account_info(),
+                    user_claim: accounts[1].clone(),
+                    authority: tournament.to_account_info(),
+                    payer: ctx.accounts.organizer.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                };
+                let cpi_program = ctx.accounts.reward_distributor_program.to_account_info();
+                let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+                reward_distributor::cpi::register_claim(cpi_ctx, pool_id, *winner, prize)?;
+            }
+
+            let mut player_profile = Account::<PlayerProfile>::try_from(&accounts[0])?;
+            require_keys_eq!(player_profile.player, *winner, TournamentError::InvalidPlayerProfile);
+
+            record_placement(&mut player_profile, rank as u32 + 1, prize);
+            player_profile.exit(&crate::ID)?;
+        }
+
+        // Settlement closes the moderation window
+        let tournament = &mut ctx.accounts.tournament;
+        tournament.prize_pool -= total_prizes;
+        tournament.prizes_distributed = total_prizes;
+        tournament.status = TournamentStatus::Settled;
+
+        let organizer_profile = &mut ctx.accounts.organizer_profile;
+        // A slashed organizer was already released from this tournament
+        if !tournament.organizer_slashed {
+            organizer_profile.active_tournaments -= 1;
+        }
+        if current_time <= settlement_deadline(tournament) {
+            organizer_profile.tournaments_settled_on_time += 1;
+        }
+
+        msg!("Prizes distributed for tournament {}", tournament.id);
+        Ok(())
+    }
+
+    /// Apply a participant's rating change after settlement (permissionless)
+    /// Uses a multiplayer Elo approximation: each player is scored against
+    /// the average rating of the rest of the field from their final rank
+    pub fn apply_rating_update(ctx: Context<ApplyRatingUpdate>) -> Result<()> {
+        let tournament = &ctx.accounts.tournament;
+        let registration = &mut ctx.accounts.registration;
+        let player_profile = &mut ctx.accounts.player_profile;
+
+        require!(tournament.status == TournamentStatus::Settled, TournamentError::InvalidStatus);
+        require!(!registration.rating_applied, TournamentError::RatingAlreadyApplied);
+        require!(!registration.disqualified, TournamentError::ParticipantDisqualified);
+
+        let field_size = tournament.current_participants as u64;
+        let old_rating = player_profile.rating;
+        if field_size >= 2 {
+            let opponents_average = (tournament.rating_sum
+                .saturating_sub(registration.rating_at_registration as u64)
+                / (field_size - 1)) as u32;
+
+            let rank_x2 = final_rank_x2(
+                &ctx.accounts.leaderboard,
+                registration,
+                tournament.completed_participants,
+                tournament.current_participants,
+            );
+            // Actual score: 1 for first place down to 0 for last
+            let actual_bps = (10_000 * (2 * field_size).saturating_sub(rank_x2 as u64)
+                / (2 * field_size - 2)) as u32;
+
+            // Apply the delta to the current rating so results from other
+            // tournaments settled in the meantime are not overwritten
+            let delta = elo_delta(registration.rating_at_registration, opponents_average, actual_bps);
+            player_profile.rating = (old_rating as i64 + delta)
+                .max(PlayerProfile::MIN_RATING as i64) as u32;
+        }
+        registration.rating_applied = true;
+
+        msg!(
+            "Rating for {} updated: {} -> {}",
+            player_profile.player,
+            old_rating,
+            player_profile.rating
+        );
+        Ok(())
+    }
+
+    /// Dispute a question after the tournament has ended
+    /// Participants stake a SOL bond that is refunded if the dispute is upheld
+    pub fn open_dispute(ctx: Context<OpenDispute>, question_index: u8) -> Result<()> {
+        let tournament = &mut ctx.accounts.tournament;
+
+        require!(tournament.status == TournamentStatus::Ended, TournamentError::TournamentNotEnded);
+        require!(
+            Clock::get()?.unix_timestamp < tournament.ended_at.unwrap() + Dispute::WINDOW_SECONDS,
+            TournamentError::DisputeWindowClosed
+        );
+        require!(question_index < tournament.question_count, TournamentError::InvalidQuestionIndex);
+        require!(
+            tournament.voided_questions & (1 << question_index) == 0,
+            TournamentError::QuestionAlreadyVoided
+        );
+
+        // Escrow the bond in the dispute account itself
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.disputer.to_account_info(),
+                to: ctx.accounts.dispute.to_account_info(),
+            },
+        );
+        anchor_lang::system_program::transfer(cpi_context, Dispute::BOND_LAMPORTS)?;
+
+        let dispute = &mut ctx.accounts.dispute;
+        dispute.tournament = tournament.key();
+        dispute.disputer = ctx.accounts.disputer.key();
+        dispute.question_index = question_index;
+        dispute.bond = Dispute::BOND_LAMPORTS;
+        dispute.status = DisputeStatus::Open;
+        dispute.created_at = Clock::get()?.unix_timestamp;
+        dispute.resolved_at = None;
+        dispute.resolver = None;
+        dispute.bump = ctx.bumps.dispute;
+
+        tournament.open_disputes += 1;
+
+        msg!(
+            "Dispute opened by {} on question {} of tournament {}",
+            dispute.disputer,
+            question_index,
+            tournament.id
+        );
+        Ok(())
+    }
+
+    /// Resolve a dispute (question_bank curators only)
+    /// Upholding voids the question and refunds the bond; rejecting slashes
+    /// the bond to the manager authority. The dispute account is closed to
+    /// the disputer either way
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, uphold: bool) -> Result<()> {
+        let curator = ctx.accounts.curator.key();
+        let tournament = &mut ctx.accounts.tournament;
+        let dispute = &mut ctx.accounts.dispute;
+
+        require!(
+            ctx.accounts.question_bank.curators.contains(&curator),
+            TournamentError::UnauthorizedCurator
+        );
+        require!(dispute.status == DisputeStatus::Open, TournamentError::DisputeAlreadyResolved);
+
+        let bond_recipient = if uphold {
+            let question_mask = 1u64 << dispute.question_index;
+            if tournament.voided_questions & question_mask == 0 {
+                tournament.voided_questions |= question_mask;
+                // Every submitted registration must be re-scored, and the
+                // leaderboard is rebuilt as they are
+                tournament.pending_rescores = tournament.completed_participants;
+                ctx.accounts.leaderboard.entries.clear();
+            }
+            dispute.status = DisputeStatus::Upheld;
+            ctx.accounts.disputer.to_account_info()
+        } else {
+            dispute.status = DisputeStatus::Rejected;
+            ctx.accounts.treasury.to_account_info()
+        };
+
+        let dispute_info = dispute.to_account_info();
+        **dispute_info.try_borrow_mut_lamports()? -= dispute.bond;
+        **bond_recipient.try_borrow_mut_lamports()? += dispute.bond;
+
+        dispute.resolved_at = Some(Clock::get()?.unix_timestamp);
+        dispute.resolver = Some(curator);
+        tournament.open_disputes -= 1;
+
+        msg!(
+            "Dispute on question {} of tournament {} resolved by {}: {:?}",
+            dispute.question_index,
+            tournament.id,
+            curator,
+            dispute.status
+        );
+        Ok(())
+    }
+
+    /// Re-score a registration after questions were voided (permissionless)
+    pub fn rescore_registration(ctx: Context<RescoreRegistration>) -> Result<()> {
+        let tournament = &mut ctx.accounts.tournament;
+        let registration = &mut ctx.accounts.registration;
+
+        require!(registration.completed, TournamentError::RegistrationUpToDate);
+        // Disqualified participants keep a zero score and stay off the board
+        require!(!registration.disqualified, TournamentError::ParticipantDisqualified);
+        require!(
+            registration.scored_voided_questions != tournament.voided_questions,
+            TournamentError::RegistrationUpToDate
+        );
+
+        registration.scored_voided_questions = tournament.voided_questions;
+        tournament.pending_rescores = tournament.pending_rescores.saturating_sub(1);
+
+        let previous_score = registration.score;
+        registration.score = compute_score(registration.correct_answers, tournament.voided_questions);
+
+        let player_profile = &mut ctx.accounts.player_profile;
+        player_profile.total_score = player_profile.total_score
+            .saturating_sub(previous_score as u64)
+            + registration.score as u64;
+
+        ctx.accounts.leaderboard.record(LeaderboardEntry {
+            participant: registration.participant,
+            score: registration.score,
+            submission_time: registration.submission_time.unwrap(),
+        });
+
+        msg!(
+            "Registration of {} re-scored to {} ({} remaining)",
+            registration.participant,
+            registration.score,
+            tournament.pending_rescores
+        );
+        Ok(())
+    }
+
+    /// Disqualify a participant (organizer or moderator only)
+    /// Zeroes their score, removes them from the leaderboard and either
+    /// forfeits their entry fee into the prize pool or refunds it
+    pub fn disqualify_participant(
+        ctx: Context<DisqualifyParticipant>,
+        reason_code: u8,
+        forfeit_entry_fee: bool,
+    ) -> Result<()> {
+        let moderator = ctx.accounts.moderator.key();
+        let tournament = &mut ctx.accounts.tournament;
+        let registration = &mut ctx.accounts.registration;
+
+        require!(
+            moderator == tournament.organizer
+                || ctx.accounts.tournament_manager.moderators.contains(&moderator),
+            TournamentError::UnauthorizedModerator
+        );
+        require!(
+            matches!(
+                tournament.status,
+                TournamentStatus::Registration | TournamentStatus::Active | TournamentStatus::Ended
+            ),
+            TournamentError::InvalidStatus
+        );
+        require!(!registration.disqualified, TournamentError::ParticipantDisqualified);
+
+        // Saved answers will never be finalized
+        if !registration.completed && registration.saved_questions != 0 {
+            tournament.pending_finalizations -= 1;
+        }
+        // Finishers leave the rated field and no longer need re-scoring
+        if registration.completed {
+            tournament.completed_participants -= 1;
+            if registration.scored_voided_questions != tournament.voided_questions {
+                tournament.pending_rescores = tournament.pending_rescores.saturating_sub(1);
+            }
+        }
+
+        let refund_amount = if forfeit_entry_fee { 0 } else { registration.entry_fee_paid };
+        if refund_amount > 0 {
+            let participant_token_account = ctx.accounts.participant_token_account
+                .as_ref()
+                .ok_or(TournamentError::MissingRefundAccount)?;
+
+            let tournament_id = tournament.id.to_le_bytes();
+            let seeds = &[
+                b"tournament".as_ref(),
+                tournament_id.as_ref(),
+                &[tournament.bump],
+            ];
+            let signer = &[&seeds[..]];
+
+            let cpi_accounts = TransferChecked {
+                from: ctx.accounts.tournament_vault.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: participant_token_account.to_account_info(),
+                authority: tournament.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+            token_interface::transfer_checked(cpi_ctx, refund_amount, ctx.accounts.token_mint.decimals)?;
+
+            tournament.prize_pool = tournament.prize_pool.saturating_sub(refund_amount);
+        }
+
+        let player_profile = &mut ctx.accounts.player_profile;
+        player_profile.total_score = player_profile.total_score.saturating_sub(registration.score as u64);
+
+        registration.score = 0;
+        registration.disqualified = true;
+        registration.disqualification_reason = reason_code;
+        registration.entry_fee_paid = 0;
+
+        tournament.current_participants = tournament.current_participants.saturating_sub(1);
+        tournament.rating_sum = tournament.rating_sum.saturating_sub(registration.rating_at_registration as u64);
+        ctx.accounts.leaderboard.remove(&registration.participant);
+
+        msg!(
+            "Participant {} disqualified from tournament {} by {} (reason: {}, refunded: {})",
+            registration.participant,
+            tournament.id,
+            moderator,
+            reason_code,
+            refund_amount
+        );
+        Ok(())
+    }
+
+    /// Add a moderator allowed to disqualify participants in any tournament
+    /// Only the manager authority can add moderators
+    pub fn add_moderator(ctx: Context<ManageModerators>, new_moderator: Pubkey) -> Result<()> {
+        let tournament_manager = &mut ctx.accounts.tournament_manager;
+
+        require!(
+            !tournament_manager.moderators.contains(&new_moderator),
+            TournamentError::ModeratorAlreadyExists
+        );
+        require!(
+            tournament_manager.moderators.len() < TournamentManagerState::MAX_MODERATORS,
+            TournamentError::TooManyModerators
+        );
+
+        tournament_manager.moderators.push(new_moderator);
+
+        msg!("Moderator added: {}", new_moderator);
+        Ok(())
+    }
+
+    /// Remove a moderator
+    /// Only the manager authority can remove moderators
+    pub fn remove_moderator(ctx: Context<ManageModerators>, moderator: Pubkey) -> Result<()> {
+        let tournament_manager = &mut ctx.accounts.tournament_manager;
+
+        let pos = tournament_manager.moderators
+            .iter()
+            .position(|&m| m == moderator)
+            .ok_or(TournamentError::ModeratorNotFound)?;
+        tournament_manager.moderators.remove(pos);
+
+        msg!("Moderator removed: {}", moderator);
+        Ok(())
+    }
+
+    /// Ban a player from registering for any tournament
+    /// Callable by the manager authority or a moderator
+    pub fn ban_player(ctx: Context<UpdateBanList>, player: Pubkey) -> Result<()> {
+        let tournament_manager = &mut ctx.accounts.tournament_manager;
+        let moderator = ctx.accounts.moderator.key();
+
+        require!(
+            moderator == tournament_manager.authority
+                || tournament_manager.moderators.contains(&moderator),
+            TournamentError::UnauthorizedModerator
+        );
+        require!(
+            !tournament_manager.banned_players.contains(&player),
+            TournamentError::PlayerAlreadyBanned
+        );
+        require!(
+            tournament_manager.banned_players.len() < TournamentManagerState::MAX_BANNED_PLAYERS,
+            TournamentError::BanListFull
+        );
+
+        tournament_manager.banned_players.push(player);
+
+        msg!("Player {} banned by {}", player, moderator);
+        Ok(())
+    }
+
+    /// Lift a player's ban
+    /// Callable by the manager authority or a moderator
+    pub fn unban_player(ctx: Context<UpdateBanList>, player: Pubkey) -> Result<()> {
+        let tournament_manager = &mut ctx.accounts.tournament_manager;
+        let moderator = ctx.accounts.moderator.key();
+
+        require!(
+            moderator == tournament_manager.authority
+                || tournament_manager.moderators.contains(&moderator),
+            TournamentError::UnauthorizedModerator
+        );
+
+        let pos = tournament_manager.banned_players
+            .iter()
+            .position(|&p| p == player)
+            .ok_or(TournamentError::PlayerNotBanned)?;
+        tournament_manager.banned_players.remove(pos);
+
+        msg!("Player {} unbanned by {}", player, moderator);
+        Ok(())
+    }
+
+    /// Create an always-open practice tournament with no entry fee or prizes
+    /// Practice tournaments share the tournament id sequence
+    pub fn create_practice_tournament(
+        ctx: Context<CreatePracticeTournament>,
+        name: String,
+        duration: i64,
+        question_count: u8,
+        category: Option<String>,
+        difficulty: Option<u8>,
+    ) -> Result<()> {
+        require!(name.len() <= 100, TournamentError::NameTooLong);
+        if let Some(category) = &category {
+            require!(category.len() <= 50, TournamentError::CategoryTooLong);
+        }
+        require!(duration > 0, TournamentError::InvalidDuration);
+        require!((5..=Tournament::MAX_QUESTIONS as u8).contains(&question_count), TournamentError::InvalidQuestionCount);
+
+        let practice = &mut ctx.accounts.practice_tournament;
+        let tournament_manager = &mut ctx.accounts.tournament_manager;
+
+        practice.id = tournament_manager.tournament_count;
+        practice.organizer = ctx.accounts.organizer.key();
+        practice.name = name;
+        practice.duration = duration;
+        practice.question_count = question_count;
+        practice.category = category;
+        practice.difficulty = difficulty;
+        practice.question_pool = Vec::new();
+        practice.total_attempts = 0;
+        practice.created_at = Clock::get()?.unix_timestamp;
+        practice.bump = ctx.bumps.practice_tournament;
+
+        let leaderboard = &mut ctx.accounts.leaderboard;
+        leaderboard.tournament = practice.key();
+        leaderboard.entries = Vec::new();
+        leaderboard.bump = ctx.bumps.leaderboard;
+
+        tournament_manager.tournament_count += 1;
+
+        msg!("Practice tournament created: {} by {}", practice.name, practice.organizer);
+        Ok(())
+    }
+
+    /// Add approved questions to a practice tournament's pool (organizer only)
+    /// Pass the question accounts as remaining accounts; each must match the
+    /// practice filter. Attempts draw their question sets from this pool
+    pub fn add_practice_questions<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AddPracticeQuestions<'info>>,
+    ) -> Result<()> {
+        let practice = &mut ctx.accounts.practice_tournament;
+
+        for question_info in ctx.remaining_accounts.iter() {
+            let question = Account::<question_bank::Question>::try_from(question_info)?;
+            require!(
+                question_matches(&question, &practice.category, practice.difficulty),
+                TournamentError::IneligibleQuestion
+            );
+            require!(
+                !practice.question_pool.contains(&question.id),
+                TournamentError::QuestionAlreadyInPool
+            );
+            require!(
+                practice.question_pool.len() < PracticeTournament::MAX_QUESTION_POOL,
+                TournamentError::QuestionPoolFull
+            );
+            practice.question_pool.push(question.id);
+        }
+
+        msg!(
+            "Practice tournament {} now has {} questions in its pool",
+            practice.id,
+            practice.question_pool.len()
+        );
+        Ok(())
+    }
+
+    /// Start a personal timed attempt at a practice tournament
+    /// Draws a fresh question set from the practice tournament's pool
+    pub fn start_attempt(ctx: Context<StartAttempt>) -> Result<()> {
+        let player = ctx.accounts.player.key();
+        require!(
+            !ctx.accounts.tournament_manager.banned_players.contains(&player),
+            TournamentError::PlayerBanned
+        );
+
+        let practice = &mut ctx.accounts.practice_tournament;
+        let attempt = &mut ctx.accounts.attempt;
+        let clock = Clock::get()?;
+
+        // An unfinished attempt blocks a new one until its time runs out
+        if let Some(started_at) = attempt.started_at {
+            require!(
+                clock.unix_timestamp > started_at + practice.duration,
+                TournamentError::AttemptInProgress
+            );
+        }
+
+        require!(
+            practice.question_pool.len() >= practice.question_count as usize,
+            TournamentError::NotEnoughQuestions
+        );
+
+        if attempt.player == Pubkey::default() {
+            attempt.player = player;
+            attempt.practice_tournament = practice.key();
+            attempt.bump = ctx.bumps.attempt;
+        }
+
+        // Not unpredictable, but practice attempts carry nothing of value
+        let seed = hashv(&[
+            player.as_ref(),
+            &attempt.attempt_count.to_le_bytes(),
+            &clock.slot.to_le_bytes(),
+            &clock.unix_timestamp.to_le_bytes(),
+        ]);
+        attempt.question_ids = draw_question_ids(&seed.to_bytes(), practice.question_count, &practice.question_pool);
+        attempt.started_at = Some(clock.unix_timestamp);
+        attempt.attempt_count += 1;
+        practice.total_attempts += 1;
+
+        msg!(
+            "Practice attempt {} started by {} in tournament {}",
+            attempt.attempt_count,
+            player,
+            practice.id
+        );
+        Ok(())
+    }
+
+    /// Submit answers for the current practice attempt
+    /// Only a player's best score is kept on the rolling leaderboard. Pass the
+    /// attempt's question accounts as remaining accounts, in `question_ids` order
+    pub fn submit_practice_answers<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SubmitPracticeAnswers<'info>>,
+        answers: Vec<i64>,
+    ) -> Result<()> {
+        let practice = &ctx.accounts.practice_tournament;
+        let attempt = &mut ctx.accounts.attempt;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        let started_at = attempt.started_at.ok_or(TournamentError::NoAttemptInProgress)?;
+        require!(current_time <= started_at + practice.duration, TournamentError::AttemptExpired);
+        require!(answers.len() == practice.question_count as usize, TournamentError::InvalidAnswerCount);
+
+        let correct_answers = grade_answers(
+            &answers,
+            question_mask(practice.question_count),
+            &attempt.question_ids,
+            ctx.remaining_accounts,
+        )?;
+        let score = compute_score(correct_answers, 0);
+
+        attempt.started_at = None;
+        attempt.last_score = score;
+        if attempt.best_submission_time.is_none() || score > attempt.best_score {
+            attempt.best_score = score;
+            attempt.best_submission_time = Some(current_time);
+
+            ctx.accounts.leaderboard.record(LeaderboardEntry {
+                participant: attempt.player,
+                score,
+                submission_time: current_time,
+            });
+        }
+
+        msg!(
+            "Practice attempt {} by {} scored {} (best: {})",
+            attempt.attempt_count,
+            attempt.player,
+            score,
+            attempt.best_score
+        );
+        Ok(())
+    }
+
+    /// Close a registration once its tournament is settled or cancelled,
+    /// returning the rent to the participant
+    /// Pass the tournament while it exists, otherwise its archive record
+    pub fn close_registration(ctx: Context<CloseRegistration>) -> Result<()> {
+        let registration = &ctx.accounts.registration;
+
+        match (&mut ctx.accounts.tournament, &ctx.accounts.archive) {
+            (Some(tournament), None) => {
+                match tournament.status {
+                    TournamentStatus::Settled => require!(
+                        registration.rating_applied || registration.disqualified,
+                        TournamentError::RatingUpdatePending
+                    ),
+                    TournamentStatus::Cancelled => require!(
+                        registration.refunded || registration.disqualified,
+                        TournamentError::RefundPending
+                    ),
+                    _ => return err!(TournamentError::InvalidStatus),
+                }
+                tournament.open_registrations -= 1;
+            }
+            // The tournament itself has already been closed
+            (None, Some(_)) => {}
+            _ => return err!(TournamentError::InvalidTournamentAccounts),
+        }
+
+        msg!(
+            "Registration of {} in tournament {} closed",
+            registration.participant,
+            registration.tournament_id
+        );
+        Ok(())
+    }
+
+    /// Close a settled, cancelled or prize-less ended tournament, returning
+    /// rent to the organizer
+    /// Requires every registration to be closed unless `CLOSE_EXPIRY` has
+    /// passed since the tournament ended. Leftover vault funds go to the
+    /// organizer and a compact summary is kept in an archive record
+    pub fn close_tournament(ctx: Context<CloseTournament>) -> Result<()> {
+        let tournament = &ctx.accounts.tournament;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        // An ended tournament with nothing left to pay out needs no settlement
+        let closable = match tournament.status {
+            TournamentStatus::Settled | TournamentStatus::Cancelled => true,
+            TournamentStatus::Ended => tournament.prize_pool == 0 && tournament.open_disputes == 0,
+            _ => false,
+        };
+        require!(closable, TournamentError::InvalidStatus);
+        let closed_since = tournament.ended_at.unwrap_or(tournament.created_at);
+        require!(
+            tournament.open_registrations == 0
+                || current_time >= closed_since + Tournament::CLOSE_EXPIRY,
+            TournamentError::OpenRegistrations
+        );
+        // Leftover funds go to the organizer, so refunds must be done first
+        // and sponsors must have taken back what is theirs
+        require!(tournament.pending_refunds == 0, TournamentError::RefundPending);
+        require!(
+            !matches!(&tournament.sponsor, Some(sponsor) if !sponsor.reclaimed),
+            TournamentError::SponsorFundsNotReclaimed
+        );
+
+        let tournament_id = tournament.id.to_le_bytes();
+        let seeds = &[
+            b"tournament".as_ref(),
+            tournament_id.as_ref(),
+            &[tournament.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let leftover = ctx.accounts.tournament_vault.amount;
+        if leftover > 0 {
+            let organizer_token_account = ctx.accounts.organizer_token_account
+                .as_ref()
+                .ok_or(TournamentError::MissingOrganizerTokenAccount)?;
+
+            let cpi_accounts = TransferChecked {
+                from: ctx.accounts.tournament_vault.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: organizer_token_account.to_account_info(),
+                authority: tournament.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+            token_interface::transfer_checked(cpi_ctx, leftover, ctx.accounts.token_mint.decimals)?;
+        }
+
+        let cpi_accounts = CloseAccount {
+            account: ctx.accounts.tournament_vault.to_account_info(),
+            destination: ctx.accounts.organizer.to_account_info(),
+            authority: tournament.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+        token_interface::close_account(cpi_ctx)?;
+
+        // Settled and cancelled tournaments were already released, as were
+        // ended ones whose organizer was slashed
+        if tournament.status == TournamentStatus::Ended && !tournament.organizer_slashed {
+            ctx.accounts.organizer_profile.active_tournaments -= 1;
+        }
+
+        let archive = &mut ctx.accounts.archive;
+        archive.tournament_id = tournament.id;
+        archive.tournament = tournament.key();
+        archive.organizer = tournament.organizer;
+        archive.winner = ctx.accounts.leaderboard.entries.first().map(|entry| entry.participant);
+        archive.prizes_distributed = tournament.prizes_distributed;
+        archive.participants = tournament.current_participants;
+        archive.status = tournament.status.clone();
+        archive.ended_at = tournament.ended_at;
+        archive.closed_at = current_time;
+        archive.bump = ctx.bumps.archive;
+
+        msg!(
+            "Tournament {} closed by {} ({} leftover returned, {} registrations still open)",
+            tournament.id,
+            tournament.organizer,
+            leftover,
+            tournament.open_registrations
+        );
+        Ok(())
+    }
+
+    /// Cancel a tournament that has not ended yet (organizer only)
+    /// Every participant must then be refunded through `refund_participant`
+    /// within `OrganizerProfile::REFUND_DEADLINE` or the organizer's bond is slashed
+    pub fn cancel_tournament(ctx: Context<CancelTournament>) -> Result<()> {
+        let tournament = &mut ctx.accounts.tournament;
+
+        require!(
+            matches!(tournament.status, TournamentStatus::Registration | TournamentStatus::Active),
+            TournamentError::InvalidStatus
+        );
+
+        tournament.status = TournamentStatus::Cancelled;
+        tournament.ended_at = Some(Clock::get()?.unix_timestamp);
+        tournament.pending_refunds = tournament.current_participants;
+
+        let organizer_profile = &mut ctx.accounts.organizer_profile;
+        organizer_profile.tournaments_cancelled += 1;
+        organizer_profile.active_tournaments -= 1;
+
+        msg!(
+            "Tournament {} cancelled by {} ({} refunds pending)",
+            tournament.id,
+            tournament.organizer,
+            tournament.pending_refunds
+        );
+        Ok(())
+    }
+
+    /// Refund a participant's entry fee from a cancelled tournament (permissionless)
+    pub fn refund_participant(ctx: Context<RefundParticipant>) -> Result<()> {
+        let tournament = &mut ctx.accounts.tournament;
+        let registration = &mut ctx.accounts.registration;
+
+        require!(tournament.status == TournamentStatus::Cancelled, TournamentError::InvalidStatus);
+        require!(!registration.disqualified, TournamentError::ParticipantDisqualified);
+        require!(!registration.refunded, TournamentError::AlreadyRefunded);
+
+        let refund_amount = registration.entry_fee_paid;
+        if refund_amount > 0 {
+            let tournament_id = tournament.id.to_le_bytes();
+            let seeds = &[
+                b"tournament".as_ref(),
+                tournament_id.as_ref(),
+                &[tournament.bump],
+            ];
+            let signer = &[&seeds[..]];
+
+            let cpi_accounts = TransferChecked {
+                from: ctx.accounts.tournament_vault.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.participant_token_account.to_account_info(),
+                authority: tournament.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+            token_interface::transfer_checked(cpi_ctx, refund_amount, ctx.accounts.token_mint.decimals)?;
+
+            tournament.prize_pool = tournament.prize_pool.saturating_sub(refund_amount);
+        }
+
+        registration.refunded = true;
+        registration.entry_fee_paid = 0;
+        tournament.pending_refunds -= 1;
+
+        msg!(
+            "Refunded {} to {} from tournament {} ({} remaining)",
+            refund_amount,
+            registration.participant,
+            tournament.id,
+            tournament.pending_refunds
+        );
+        Ok(())
+    }
+
+    /// Register as a tournament organizer by posting a SOL bond
+    pub fn register_organizer(ctx: Context<RegisterOrganizer>, bond: u64) -> Result<()> {
+        require!(bond >= OrganizerProfile::MIN_BOND, TournamentError::InsufficientOrganizerBond);
+
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.organizer.to_account_info(),
+                to: ctx.accounts.organizer_profile.to_account_info(),
+            },
+        );
+        anchor_lang::system_program::transfer(cpi_context, bond)?;
+
+        let organizer_profile = &mut ctx.accounts.organizer_profile;
+        organizer_profile.organizer = ctx.accounts.organizer.key();
+        organizer_profile.bond = bond;
+        organizer_profile.verified = false;
+        organizer_profile.registered_at = Clock::get()?.unix_timestamp;
+        organizer_profile.bump = ctx.bumps.organizer_profile;
+
+        msg!("Organizer {} registered with bond {}", organizer_profile.organizer, bond);
+        Ok(())
+    }
+
+    /// Top up an organizer's bond
+    pub fn deposit_organizer_bond(ctx: Context<UpdateOrganizerBond>, amount: u64) -> Result<()> {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.organizer.to_account_info(),
+                to: ctx.accounts.organizer_profile.to_account_info(),
+            },
+        );
+        anchor_lang::system_program::transfer(cpi_context, amount)?;
+
+        let organizer_profile = &mut ctx.accounts.organizer_profile;
+        organizer_profile.bond += amount;
+
+        msg!("Organizer {} bond increased to {}", organizer_profile.organizer, organizer_profile.bond);
+        Ok(())
+    }
+
+    /// Withdraw bond once the organizer has no unsettled tournaments
+    pub fn withdraw_organizer_bond(ctx: Context<UpdateOrganizerBond>, amount: u64) -> Result<()> {
+        let organizer_profile = &mut ctx.accounts.organizer_profile;
+
+        require!(organizer_profile.active_tournaments == 0, TournamentError::OrganizerHasActiveTournaments);
+        require!(amount <= organizer_profile.bond, TournamentError::InsufficientOrganizerBond);
+
+        organizer_profile.bond -= amount;
+        **organizer_profile.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.organizer.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        msg!("Organizer {} withdrew {} of bond", organizer_profile.organizer, amount);
+        Ok(())
+    }
+
+    /// Set or clear an organizer's verified flag (manager authority only)
+    pub fn set_organizer_verified(ctx: Context<SetOrganizerVerified>, verified: bool) -> Result<()> {
+        let organizer_profile = &mut ctx.accounts.organizer_profile;
+        organizer_profile.verified = verified;
+
+        msg!("Organizer {} verified: {}", organizer_profile.organizer, verified);
+        Ok(())
+    }
+
+    /// Slash an organizer's bond into the treasury (permissionless)
+    /// Applies when a cancelled tournament still owes refunds after the
+    /// refund deadline, or an ended tournament was never settled in time
+    pub fn slash_organizer(ctx: Context<SlashOrganizer>) -> Result<()> {
+        let tournament = &mut ctx.accounts.tournament;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(!tournament.organizer_slashed, TournamentError::OrganizerAlreadySlashed);
+        let slashable = match tournament.status {
+            TournamentStatus::Cancelled => {
+                tournament.pending_refunds > 0
+                    && current_time > tournament.ended_at.unwrap() + OrganizerProfile::REFUND_DEADLINE
+            }
+            TournamentStatus::Ended => {
+                tournament.open_disputes == 0 && current_time > settlement_deadline(tournament)
+            }
+            _ => false,
+        };
+        require!(slashable, TournamentError::OrganizerNotSlashable);
+
+        let organizer_profile = &mut ctx.accounts.organizer_profile;
+        let slash_amount = (organizer_profile.bond as u128 * OrganizerProfile::SLASH_BPS as u128 / 10_000) as u64;
+
+        organizer_profile.bond -= slash_amount;
+        organizer_profile.tournaments_slashed += 1;
+        organizer_profile.total_slashed += slash_amount;
+        // Cancelled tournaments were released when cancelled; an unsettled
+        // one is released here so the rest of the bond is not locked forever
+        if tournament.status == TournamentStatus::Ended {
+            organizer_profile.active_tournaments -= 1;
+        }
+        **organizer_profile.to_account_info().try_borrow_mut_lamports()? -= slash_amount;
+        **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += slash_amount;
+
+        tournament.organizer_slashed = true;
+
+        msg!(
+            "Organizer {} slashed {} over tournament {}",
+            organizer_profile.organizer,
+            slash_amount,
+            tournament.id
+        );
+        Ok(())
+    }
+}
+
+/// How long winners have to claim their prizes from the reward pool
+const PRIZE_CLAIM_PERIOD: i64 = 30 * 24 * 60 * 60;
+
+/// Latest time an ended tournament can be settled without slashing
+fn settlement_deadline(tournament: &Tournament) -> i64 {
+    tournament.ended_at.unwrap() + Dispute::WINDOW_SECONDS + OrganizerProfile::SETTLEMENT_DEADLINE
+}
+
+/// Points awarded per correctly answered, non-voided question
+const POINTS_PER_CORRECT_ANSWER: u32 = 10;
+
+/// Score a registration from its per-question correctness bitmap, ignoring
+/// any questions voided through disputes.
+fn compute_score(correct_answers: u64, voided_questions: u64) -> u32 {
+    (correct_answers & !voided_questions).count_ones() * POINTS_PER_CORRECT_ANSWER
+}
+
+/// Elo K-factor: the largest rating change a single result can cause
+const RATING_K_FACTOR: i64 = 32;
+
+/// Expected score (in basis points) for rating differences of 0, 25, ..., 800,
+/// i.e. 1 / (1 + 10^(-d / 400)). Negative differences use 10_000 - E(|d|).
+const ELO_EXPECTED_BPS: [u32; 33] = [
+    5000, 5359, 5715, 6063, 6401, 6725, 7034, 7325, 7597, 7850, 8083,
+    8296, 8490, 8666, 8823, 8965, 9091, 9203, 9302, 9390, 9468, 9536,
+    9595, 9648, 9693, 9733, 9768, 9799, 9825, 9848, 9868, 9886, 9901,
+];
+
+/// Fixed-point Elo expected score of `rating` against `opponent_rating`,
+/// in basis points, linearly interpolated from `ELO_EXPECTED_BPS`.
+fn elo_expected_bps(rating: u32, opponent_rating: u32) -> u32 {
+    let diff = (rating as i64 - opponent_rating as i64).clamp(-800, 800);
+    let step = diff.unsigned_abs() as usize / 25;
+    let remainder = diff.unsigned_abs() as u32 % 25;
+
+    let low = ELO_EXPECTED_BPS[step];
+    let high = ELO_EXPECTED_BPS[(step + 1).min(ELO_EXPECTED_BPS.len() - 1)];
+    let expected = low + (high - low) * remainder / 25;
+
+    if diff >= 0 { expected } else { 10_000 - expected }
+}
+
+/// Rating change after scoring `actual_bps` (0 = loss, 10_000 = win) against
+/// a field whose average rating is `opponent_rating`
+fn elo_delta(rating: u32, opponent_rating: u32, actual_bps: u32) -> i64 {
+    let expected_bps = elo_expected_bps(rating, opponent_rating) as i64;
+    RATING_K_FACTOR * (actual_bps as i64 - expected_bps) / 10_000
+}
+
+/// Twice a registration's final rank (1 = first). Leaderboard entries have an
+/// exact rank; other finishers share the average of the ranks below the board
+/// and non-finishers share the ranks below every finisher. Both counts
+/// exclude disqualified participants, so ranks span exactly the
+/// `current_participants` field that ratings are computed over.
+fn final_rank_x2(
+    leaderboard: &Leaderboard,
+    registration: &Registration,
+    completed_participants: u32,
+    current_participants: u32,
+) -> u32 {
+    if let Some(pos) = leaderboard.entries
+        .iter()
+        .position(|e| e.participant == registration.participant)
+    {
+        return 2 * (pos as u32 + 1);
+    }
+
+    if registration.completed {
+        leaderboard.entries.len() as u32 + 1 + completed_participants
+    } else {
+        completed_participants + 1 + current_participants
+    }
+}
+
+/// Fold a final placement into a player's profile. Ranks start at 1.
+fn record_placement(player_profile: &mut PlayerProfile, rank: u32, winnings: u64) {
+    if rank == 1 {
+        player_profile.tournaments_won += 1;
+    }
+    if player_profile.best_rank == 0 || rank < player_profile.best_rank {
+        player_profile.best_rank = rank;
+    }
+    player_profile.total_winnings += winnings;
+}
+
+/// Split `question_count` across mix buckets by weight (largest remainder)
+fn mix_bucket_counts(buckets: &[QuestionMixEntry], question_count: u8) -> Vec<u8> {
+    let shares: Vec<u32> = buckets
+        .iter()
+        .map(|bucket| bucket.weight_bps as u32 * question_count as u32)
+        .collect();
+    let mut counts: Vec<u8> = shares.iter().map(|share| (share / 10_000) as u8).collect();
+
+    // Hand the leftover questions to the largest remainders, one each.
+    // The sort is stable, so earlier buckets win ties
+    let leftover = (question_count - counts.iter().sum::<u8>()) as usize;
+    let mut by_remainder: Vec<usize> = (0..buckets.len()).collect();
+    by_remainder.sort_by_key(|&i| std::cmp::Reverse(shares[i] % 10_000));
+    for &index in by_remainder.iter().take(leftover) {
+        counts[index] += 1;
+    }
+    counts
+}
+
+/// Bitmask with the lowest `count` bits set
+fn question_mask(count: u8) -> u64 {
+    if count >= 64 { u64::MAX } else { (1u64 << count) - 1 }
+}
+
+/// Bitmask of the answered questions that were answered correctly
+/// `questions` must be the question accounts for `question_ids`, in order
+fn grade_answers<'info>(
+    answers: &[i64],
+    answered: u64,
+    question_ids: &[u64],
+    questions: &'info [AccountInfo<'info>],
+) -> Result<u64> {
+    require!(
+        question_ids.len() == answers.len() && questions.len() == answers.len(),
+        TournamentError::InvalidQuestionAccounts
+    );
+
+    let mut correct_answers: u64 = 0;
+    for (i, ((answer, question_id), question_info)) in answers
+        .iter()
+        .zip(question_ids.iter())
+        .zip(questions.iter())
+        .enumerate()
+    {
+        let question = Account::<question_bank::Question>::try_from(question_info)?;
+        require!(question.id == *question_id, TournamentError::InvalidQuestionAccounts);
+
+        if answered & (1 << i) != 0 && question_bank::check_answer(&question.question_type, *answer) {
+            correct_answers |= 1 << i;
+        }
+    }
+    Ok(correct_answers)
+}
+
+/// Score the saved answers of a registration and mark it completed
+fn finalize_registration<'info>(
+    tournament: &mut Tournament,
+    registration: &mut Registration,
+    player_profile: &mut PlayerProfile,
+    leaderboard: &mut Leaderboard,
+    questions: &'info [AccountInfo<'info>],
+    submission_time: i64,
+) -> Result<()> {
+    let correct_answers = grade_answers(
+        &registration.answers,
+        registration.saved_questions,
+        &tournament.question_ids,
+        questions,
+    )?;
+    let score = compute_score(correct_answers, tournament.voided_questions);
+
+    registration.score = score;
+    registration.correct_answers = correct_answers;
+    registration.scored_voided_questions = tournament.voided_questions;
+    registration.completed = true;
+    registration.submission_time = Some(submission_time);
+
+    tournament.completed_participants += 1;
+
+    player_profile.tournaments_completed += 1;
+    player_profile.total_score += score as u64;
+
+    leaderboard.record(LeaderboardEntry {
+        participant: registration.participant,
+        score,
+        submission_time,
+    });
+    Ok(())
+}
+
+/// Whether an approved question fits an optional category and difficulty filter
+fn question_matches(
+    question: &question_bank::Question,
+    category: &Option<String>,
+    difficulty: Option<u8>,
+) -> bool {
+    let category_matches = match category {
+        Some(category) => *category == question.category,
+        None => true,
+    };
+    let difficulty_matches = match difficulty {
+        Some(difficulty) => difficulty == question.difficulty,
+        None => true,
+    };
+    question.status == question_bank::QuestionStatus::Approved && category_matches && difficulty_matches
+}
+
+/// Draw `count` distinct question ids from a pool of distinct ids using a seed
+fn draw_question_ids(seed: &[u8; 32], count: u8, pool: &[u64]) -> Vec<u64> {
+    let mut question_ids: Vec<u64> = Vec::with_capacity(count as usize);
+    let mut state = *seed;
+    while question_ids.len() < count as usize {
+        state = hashv(&[&state]).to_bytes();
+        let index = u64::from_le_bytes(state[..8].try_into().unwrap()) % pool.len() as u64;
+        let candidate = pool[index as usize];
+        if !question_ids.contains(&candidate) {
+            question_ids.push(candidate);
+        }
+    }
+    question_ids
+}
+
+/// Gameplay instructions accept either the participant or a live session key
+fn authorize_gameplay(
+    registration: &Account<Registration>,
+    signer: &Pubkey,
+    game_session: Option<&Account<GameSession>>,
+    current_time: i64,
+) -> Result<()> {
+    if *signer == registration.participant {
+        return Ok(());
+    }
+
+    let game_session = game_session.ok_or(TournamentError::UnauthorizedSessionKey)?;
+    require_keys_eq!(game_session.registration, registration.key(), TournamentError::UnauthorizedSessionKey);
+    require_keys_eq!(game_session.session_key, *signer, TournamentError::UnauthorizedSessionKey);
+    require!(current_time < game_session.expires_at, TournamentError::SessionExpired);
+
+    Ok(())
+}
+
+/// Move a tournament from `Registration` to `Active`. Shared by the organizer
+/// and crank paths so both enforce the same preconditions, except that only
+/// the organizer may start before `start_time`.
+fn activate_tournament(tournament: &mut Tournament, current_time: i64, allow_early: bool) -> Result<()> {
+    require!(tournament.status == TournamentStatus::Registration, TournamentError::InvalidStatus);
+    require!(allow_early || current_time >= tournament.start_time, TournamentError::TournamentNotReady);
+    require!(tournament.current_participants >= 2, TournamentError::InsufficientParticipants);
+    // Answers are graded against the selected questions
+    require!(
+        tournament.question_ids.len() == tournament.question_count as usize,
+        TournamentError::QuestionsNotSelected
+    );
+
+    tournament.status = TournamentStatus::Active;
+    tournament.actual_start_time = Some(current_time);
+    Ok(())
+}
+
+/// Move a tournament from `Active` to `Ended` once its duration has elapsed,
+/// or at any time if `allow_early` (organizer path).
+fn conclude_tournament(tournament: &mut Tournament, current_time: i64, allow_early: bool) -> Result<()> {
+    require!(tournament.status == TournamentStatus::Active, TournamentError::TournamentNotActive);
+
+    let tournament_end_time = tournament.actual_start_time.unwrap() + tournament.duration;
+    require!(allow_early || current_time >= tournament_end_time, TournamentError::TournamentNotEnded);
+
+    tournament.status = TournamentStatus::Ended;
+    tournament.ended_at = Some(current_time);
+    Ok(())
+}
+
+/// Pay `amount` lamports of the escrowed crank bounty from the tournament
+/// account to the cranker. The bounty sits on top of the rent-exempt minimum.
+fn pay_crank_reward<'info>(
+    tournament: &mut Account<'info, Tournament>,
+    cranker: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    tournament.crank_bounty = tournament.crank_bounty
+        .checked_sub(amount)
+        .ok_or(TournamentError::ArithmeticOverflow)?;
+
+    let tournament_info = tournament.to_account_info();
+    **tournament_info.try_borrow_mut_lamports()? -= amount;
+    **cranker.try_borrow_mut_lamports()? += amount;
+    Ok(())
+}
+
+/// Reject Token-2022 mints carrying extensions the tournament cannot honor,
+/// using the same list as reward pools.
+fn validate_mint_extensions(mint_info: &AccountInfo) -> Result<()> {
+    require!(
+        reward_distributor::mint_extensions_supported(mint_info)?,
+        TournamentError::UnsupportedMintExtension
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + TournamentManagerState::SPACE,
+        seeds = [b"tournament_manager"],
+        bump
+    )]
+    pub tournament_manager: Account<'info, TournamentManagerState>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateTournament<'info> {
+    #[account(
+        init,
+        payer = organizer,
+        space = 8 + Tournament::SPACE,
+        seeds = [b"tournament", tournament_manager.tournament_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    #[account(
+        mut,
+        seeds = [b"tournament_manager"],
+        bump = tournament_manager.bump
+    )]
+    pub tournament_manager: Account<'info, TournamentManagerState>,
+    
+    #[account(
+        init,
+        payer = organizer,
+        space = 8 + Leaderboard::SPACE,
+        seeds = [b"leaderboard", tournament.key().as_ref()],
+        bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+    
+    #[account(
+        init,
+        payer = organizer,
+        seeds = [b"tournament_vault", tournament.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = tournament,
+        token::token_program = token_program
+    )]
+    pub tournament_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(mint::token_program = token_program)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [b"organizer", organizer.key().as_ref()],
+        bump = organizer_profile.bump
+    )]
+    pub organizer_profile: Account<'info, OrganizerProfile>,
+    
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTournament<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        has_one = organizer
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    pub organizer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterForTournament<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament_manager"],
+        bump = tournament_manager.bump
+    )]
+    pub tournament_manager: Account<'info, TournamentManagerState>,
+    
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    #[account(
+        init,
+        payer = participant,
+        space = 8 + Registration::SPACE,
+        seeds = [b"registration", tournament.key().as_ref(), participant.key().as_ref()],
+        bump
+    )]
+    pub registration: Account<'info, Registration>,
+    
+    #[account(
+        init_if_needed,
+        payer = participant,
+        space = 8 + PlayerProfile::SPACE,
+        seeds = [b"player", participant.key().as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(mut)]
+    pub participant: Signer<'info>,
+    
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = participant,
+        token::token_program = token_program
+    )]
+    pub participant_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"tournament_vault", tournament.key().as_ref()],
+        bump
+    )]
+    pub tournament_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(address = tournament.token_mint @ TournamentError::InvalidTokenMint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    
+    /// Holding of the sponsor's token, when the sponsor requires one
+    pub sponsor_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SponsorTournament<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        has_one = organizer
+    )]
+    pub tournament: Box<Account<'info, Tournament>>,
+    
+    pub organizer: Signer<'info>,
+    
+    pub sponsor: Signer<'info>,
+    
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = sponsor,
+        token::token_program = token_program
+    )]
+    pub sponsor_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"tournament_vault", tournament.key().as_ref()],
+        bump
+    )]
+    pub tournament_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(address = tournament.token_mint @ TournamentError::InvalidTokenMint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimSponsorFunds<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump
+    )]
+    pub tournament: Box<Account<'info, Tournament>>,
+    
+    pub sponsor: Signer<'info>,
+    
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = sponsor,
+        token::token_program = token_program
+    )]
+    pub sponsor_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"tournament_vault", tournament.key().as_ref()],
+        bump
+    )]
+    pub tournament_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(address = tournament.token_mint @ TournamentError::InvalidTokenMint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    /// CHECK: Prize pool, validated by reward_distributor
+    #[account(mut)]
+    pub reward_pool: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Prize pool vault authority, validated by reward_distributor
+    #[account(mut)]
+    pub reward_vault: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Prize pool token account, validated by reward_distributor
+    #[account(mut)]
+    pub reward_vault_token: Option<UncheckedAccount<'info>>,
+    
+    pub reward_distributor_program: Option<Program<'info, reward_distributor::program::RewardDistributor>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct StartTournament<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        has_one = organizer
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    pub organizer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CrankTournament<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitAnswers<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    #[account(
+        mut,
+        seeds = [b"registration", tournament.key().as_ref(), participant.key().as_ref()],
+        bump = registration.bump,
+        has_one = participant
+    )]
+    pub registration: Account<'info, Registration>,
+    
+    #[account(
+        mut,
+        seeds = [b"leaderboard", tournament.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+    
+    #[account(
+        mut,
+        seeds = [b"player", participant.key().as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// CHECK: Owner of the registration, enforced by its seeds and `has_one`
+    pub participant: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"session", registration.key().as_ref()],
+        bump = game_session.bump
+    )]
+    pub game_session: Option<Account<'info, GameSession>>,
+    
+    /// Participant, or the session key of `game_session`
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SaveAnswers<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    #[account(
+        mut,
+        seeds = [b"registration", tournament.key().as_ref(), participant.key().as_ref()],
+        bump = registration.bump,
+        has_one = participant
+    )]
+    pub registration: Account<'info, Registration>,
+    
+    /// CHECK: Owner of the registration, enforced by its seeds and `has_one`
+    pub participant: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"session", registration.key().as_ref()],
+        bump = game_session.bump
+    )]
+    pub game_session: Option<Account<'info, GameSession>>,
+    
+    /// Participant, or the session key of `game_session`
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CrankFinalizeAnswers<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    #[account(
+        mut,
+        seeds = [b"registration", tournament.key().as_ref(), registration.participant.as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, Registration>,
+    
+    #[account(
+        mut,
+        seeds = [b"player", registration.participant.as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        mut,
+        seeds = [b"leaderboard", tournament.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+}
+
+#[derive(Accounts)]
+pub struct CreateGameSession<'info> {
+    #[account(
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    #[account(
+        seeds = [b"registration", tournament.key().as_ref(), participant.key().as_ref()],
+        bump = registration.bump,
+        has_one = participant
+    )]
+    pub registration: Account<'info, Registration>,
+    
+    #[account(
+        init,
+        payer = participant,
+        space = 8 + GameSession::SPACE,
+        seeds = [b"session", registration.key().as_ref()],
+        bump
+    )]
+    pub game_session: Account<'info, GameSession>,
+    
+    #[account(mut)]
+    pub participant: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeGameSession<'info> {
+    #[account(
+        mut,
+        seeds = [b"session", game_session.registration.as_ref()],
+        bump = game_session.bump,
+        has_one = participant,
+        close = participant
+    )]
+    pub game_session: Account<'info, GameSession>,
+    
+    #[account(mut)]
+    pub participant: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApplyRatingUpdate<'info> {
+    #[account(
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    #[account(
+        mut,
+        seeds = [b"registration", tournament.key().as_ref(), registration.participant.as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, Registration>,
+    
+    #[account(
+        seeds = [b"leaderboard", tournament.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+    
+    #[account(
+        mut,
+        seeds = [b"player", registration.participant.as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+}
+
+#[derive(Accounts)]
+#[instruction(question_index: u8)]
+pub struct OpenDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    /// Only participants can dispute questions
+    #[account(
+        seeds = [b"registration", tournament.key().as_ref(), disputer.key().as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, Registration>,
+    
+    #[account(
+        init,
+        payer = disputer,
+        space = 8 + Dispute::SPACE,
+        seeds = [b"dispute", tournament.key().as_ref(), &[question_index], disputer.key().as_ref()],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+    
+    #[account(mut)]
+    pub disputer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        seeds = [b"tournament_manager"],
+        bump = tournament_manager.bump
+    )]
+    pub tournament_manager: Account<'info, TournamentManagerState>,
+    
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    #[account(
+        mut,
+        seeds = [b"dispute", tournament.key().as_ref(), &[dispute.question_index], disputer.key().as_ref()],
+        bump = dispute.bump,
+        has_one = disputer,
+        close = disputer
+    )]
+    pub dispute: Account<'info, Dispute>,
+    
+    #[account(
+        mut,
+        seeds = [b"leaderboard", tournament.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+    
+    #[account(
+        seeds = [b"question_bank"],
+        bump = question_bank.bump,
+        seeds::program = question_bank::ID
+    )]
+    pub question_bank: Account<'info, question_bank::QuestionBank>,
+    
+    /// CHECK: Receives the bond back when the dispute is upheld, and the
+    /// dispute account's rent either way
+    #[account(mut)]
+    pub disputer: UncheckedAccount<'info>,
+    
+    /// CHECK: Receives slashed bonds
+    #[account(mut, address = tournament_manager.authority)]
+    pub treasury: UncheckedAccount<'info>,
+    
+    pub curator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RescoreRegistration<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    #[account(
+        mut,
+        seeds = [b"registration", tournament.key().as_ref(), registration.participant.as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, Registration>,
+    
+    #[account(
+        mut,
+        seeds = [b"player", registration.participant.as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        mut,
+        seeds = [b"leaderboard", tournament.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+}
+
+#[derive(Accounts)]
+pub struct EndTournament<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        has_one = organizer
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    pub organizer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DistributePrizes<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        has_one = organizer
+    )]
+    pub tournament: Box<Account<'info, Tournament>>,
+    
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"organizer", organizer.key().as_ref()],
+        bump = organizer_profile.bump
+    )]
+    pub organizer_profile: Box<Account<'info, OrganizerProfile>>,
+    
+    #[account(
+        seeds = [b"leaderboard", tournament.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    
+    #[account(
+        mut,
+        seeds = [b"tournament_vault", tournament.key().as_ref()],
+        bump
+    )]
+    pub tournament_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(address = tournament.token_mint @ TournamentError::InvalidTokenMint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    /// CHECK: Prize pool, initialized by reward_distributor
+    #[account(mut)]
+    pub reward_pool: UncheckedAccount<'info>,
+    
+    /// CHECK: Prize pool vault authority, initialized by reward_distributor
+    #[account(mut)]
+    pub reward_vault: UncheckedAccount<'info>,
+    
+    /// CHECK: Prize pool token account, initialized by reward_distributor
+    #[account(mut)]
+    pub reward_vault_token: UncheckedAccount<'info>,
+    
+    pub reward_distributor_program: Program<'info, reward_distributor::program::RewardDistributor>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DisqualifyParticipant<'info> {
+    #[account(
+        seeds = [b"tournament_manager"],
+        bump = tournament_manager.bump
+    )]
+    pub tournament_manager: Account<'info, TournamentManagerState>,
+    
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    #[account(
+        mut,
+        seeds = [b"registration", tournament.key().as_ref(), registration.participant.as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, Registration>,
+    
+    #[account(
+        mut,
+        seeds = [b"player", registration.participant.as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        mut,
+        seeds = [b"leaderboard", tournament.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+    
+    #[account(
+        mut,
+        seeds = [b"tournament_vault", tournament.key().as_ref()],
+        bump
+    )]
+    pub tournament_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Receives the entry fee refund when it is not forfeited
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = registration.participant,
+        token::token_program = token_program
+    )]
+    pub participant_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(address = tournament.token_mint @ TournamentError::InvalidTokenMint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    
+    pub moderator: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ManageModerators<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament_manager"],
+        bump = tournament_manager.bump,
+        has_one = authority @ TournamentError::UnauthorizedAuthority
+    )]
+    pub tournament_manager: Account<'info, TournamentManagerState>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateBanList<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament_manager"],
+        bump = tournament_manager.bump
+    )]
+    pub tournament_manager: Account<'info, TournamentManagerState>,
+    
+    pub moderator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreatePracticeTournament<'info> {
+    #[account(
+        init,
+        payer = organizer,
+        space = 8 + PracticeTournament::SPACE,
+        seeds = [b"practice", tournament_manager.tournament_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub practice_tournament: Account<'info, PracticeTournament>,
+    
+    #[account(
+        mut,
+        seeds = [b"tournament_manager"],
+        bump = tournament_manager.bump
+    )]
+    pub tournament_manager: Account<'info, TournamentManagerState>,
+    
+    #[account(
+        init,
+        payer = organizer,
+        space = 8 + Leaderboard::SPACE,
+        seeds = [b"leaderboard", practice_tournament.key().as_ref()],
+        bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+    
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
 
-        msg!("Prizes distributed for tournament {}", tournament.id);
-        Ok(())
-    }
+#[derive(Accounts)]
+pub struct AddPracticeQuestions<'info> {
+    #[account(
+        mut,
+        seeds = [b"practice", practice_tournament.id.to_le_bytes().as_ref()],
+        bump = practice_tournament.bump,
+        has_one = organizer
+    )]
+    pub practice_tournament: Account<'info, PracticeTournament>,
+    
+    pub organizer: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct Initialize<'info> {
+pub struct StartAttempt<'info> {
     #[account(
-        init,
-        payer = authority,
-        space = 8 + TournamentManagerState::SPACE,
         seeds = [b"tournament_manager"],
-        bump
+        bump = tournament_manager.bump
     )]
     pub tournament_manager: Account<'info, TournamentManagerState>,
     
+    #[account(
+        mut,
+        seeds = [b"practice", practice_tournament.id.to_le_bytes().as_ref()],
+        bump = practice_tournament.bump
+    )]
+    pub practice_tournament: Account<'info, PracticeTournament>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PracticeAttempt::SPACE,
+        seeds = [b"attempt", practice_tournament.key().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub attempt: Account<'info, PracticeAttempt>,
+    
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub player: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct CreateTournament<'info> {
+pub struct SubmitPracticeAnswers<'info> {
     #[account(
-        init,
-        payer = organizer,
-        space = 8 + Tournament::SPACE,
-        seeds = [b"tournament", tournament_manager.tournament_count.to_le_bytes().as_ref()],
-        bump
+        seeds = [b"practice", practice_tournament.id.to_le_bytes().as_ref()],
+        bump = practice_tournament.bump
     )]
-    pub tournament: Account<'info, Tournament>,
+    pub practice_tournament: Account<'info, PracticeTournament>,
     
     #[account(
         mut,
-        seeds = [b"tournament_manager"],
-        bump = tournament_manager.bump
+        seeds = [b"attempt", practice_tournament.key().as_ref(), player.key().as_ref()],
+        bump = attempt.bump,
+        has_one = player
     )]
-    pub tournament_manager: Account<'info, TournamentManagerState>,
+    pub attempt: Account<'info, PracticeAttempt>,
     
-    #[account(mut)]
-    pub organizer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard", practice_tournament.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
     
-    pub system_program: Program<'info, System>,
+    pub player: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct RegisterForTournament<'info> {
+pub struct CloseRegistration<'info> {
     #[account(
         mut,
-        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        seeds = [b"tournament", registration.tournament_id.to_le_bytes().as_ref()],
         bump = tournament.bump
     )]
-    pub tournament: Account<'info, Tournament>,
+    pub tournament: Option<Account<'info, Tournament>>,
     
     #[account(
-        init,
-        payer = participant,
-        space = 8 + Registration::SPACE,
-        seeds = [b"registration", tournament.key().as_ref(), participant.key().as_ref()],
-        bump
+        seeds = [b"archive", registration.tournament_id.to_le_bytes().as_ref()],
+        bump = archive.bump
+    )]
+    pub archive: Option<Account<'info, TournamentArchive>>,
+    
+    #[account(
+        mut,
+        has_one = participant,
+        close = participant
     )]
     pub registration: Account<'info, Registration>,
     
     #[account(mut)]
     pub participant: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseTournament<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        has_one = organizer,
+        close = organizer
+    )]
+    pub tournament: Box<Account<'info, Tournament>>,
     
-    #[account(mut)]
-    pub participant_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard", tournament.key().as_ref()],
+        bump = leaderboard.bump,
+        close = organizer
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    
+    #[account(
+        mut,
+        seeds = [b"tournament_vault", tournament.key().as_ref()],
+        bump
+    )]
+    pub tournament_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub organizer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    #[account(address = tournament.token_mint @ TournamentError::InvalidTokenMint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        seeds = [b"organizer", organizer.key().as_ref()],
+        bump = organizer_profile.bump
+    )]
+    pub organizer_profile: Box<Account<'info, OrganizerProfile>>,
+    
+    #[account(
+        init,
+        payer = organizer,
+        space = 8 + TournamentArchive::SPACE,
+        seeds = [b"archive", tournament.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub archive: Account<'info, TournamentArchive>,
     
     #[account(mut)]
-    pub tournament_vault: Account<'info, TokenAccount>,
+    pub organizer: Signer<'info>,
     
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct StartTournament<'info> {
+pub struct CancelTournament<'info> {
     #[account(
         mut,
         seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
@@ -275,12 +2953,20 @@ pub struct StartTournament<'info> {
     )]
     pub tournament: Account<'info, Tournament>,
     
+    #[account(
+        mut,
+        seeds = [b"organizer", organizer.key().as_ref()],
+        bump = organizer_profile.bump
+    )]
+    pub organizer_profile: Account<'info, OrganizerProfile>,
+    
     pub organizer: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct SubmitAnswers<'info> {
+pub struct RefundParticipant<'info> {
     #[account(
+        mut,
         seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
         bump = tournament.bump
     )]
@@ -288,43 +2974,109 @@ pub struct SubmitAnswers<'info> {
     
     #[account(
         mut,
-        seeds = [b"registration", tournament.key().as_ref(), participant.key().as_ref()],
-        bump = registration.bump,
-        has_one = participant
+        seeds = [b"registration", tournament.key().as_ref(), registration.participant.as_ref()],
+        bump = registration.bump
     )]
     pub registration: Account<'info, Registration>,
     
-    pub participant: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"tournament_vault", tournament.key().as_ref()],
+        bump
+    )]
+    pub tournament_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = registration.participant,
+        token::token_program = token_program
+    )]
+    pub participant_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(address = tournament.token_mint @ TournamentError::InvalidTokenMint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 #[derive(Accounts)]
-pub struct EndTournament<'info> {
+pub struct RegisterOrganizer<'info> {
+    #[account(
+        init,
+        payer = organizer,
+        space = 8 + OrganizerProfile::SPACE,
+        seeds = [b"organizer", organizer.key().as_ref()],
+        bump
+    )]
+    pub organizer_profile: Account<'info, OrganizerProfile>,
+    
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateOrganizerBond<'info> {
     #[account(
         mut,
-        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
-        bump = tournament.bump,
+        seeds = [b"organizer", organizer.key().as_ref()],
+        bump = organizer_profile.bump,
         has_one = organizer
     )]
-    pub tournament: Account<'info, Tournament>,
+    pub organizer_profile: Account<'info, OrganizerProfile>,
     
+    #[account(mut)]
     pub organizer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct DistributePrizes<'info> {
+pub struct SetOrganizerVerified<'info> {
+    #[account(
+        seeds = [b"tournament_manager"],
+        bump = tournament_manager.bump,
+        has_one = authority @ TournamentError::UnauthorizedAuthority
+    )]
+    pub tournament_manager: Account<'info, TournamentManagerState>,
+    
+    #[account(
+        mut,
+        seeds = [b"organizer", organizer_profile.organizer.as_ref()],
+        bump = organizer_profile.bump
+    )]
+    pub organizer_profile: Account<'info, OrganizerProfile>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SlashOrganizer<'info> {
+    #[account(
+        seeds = [b"tournament_manager"],
+        bump = tournament_manager.bump
+    )]
+    pub tournament_manager: Account<'info, TournamentManagerState>,
+    
     #[account(
+        mut,
         seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
-        bump = tournament.bump,
-        has_one = organizer
+        bump = tournament.bump
     )]
     pub tournament: Account<'info, Tournament>,
     
-    pub organizer: Signer<'info>,
-    
-    #[account(mut)]
-    pub tournament_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"organizer", tournament.organizer.as_ref()],
+        bump = organizer_profile.bump
+    )]
+    pub organizer_profile: Account<'info, OrganizerProfile>,
     
-    pub token_program: Program<'info, Token>,
+    /// CHECK: Receives slashed bonds
+    #[account(mut, address = tournament_manager.authority)]
+    pub treasury: UncheckedAccount<'info>,
 }
 
 #[account]
@@ -332,11 +3084,15 @@ pub struct TournamentManagerState {
     pub authority: Pubkey,
     pub tournament_count: u64,
     pub total_participants: u64,
+    pub moderators: Vec<Pubkey>,
+    pub banned_players: Vec<Pubkey>,
     pub bump: u8,
 }
 
 impl TournamentManagerState {
-    pub const SPACE: usize = 32 + 8 + 8 + 1;
+    pub const MAX_MODERATORS: usize = 10;
+    pub const MAX_BANNED_PLAYERS: usize = 100;
+    pub const SPACE: usize = 32 + 8 + 8 + (4 + 32 * Self::MAX_MODERATORS) + (4 + 32 * Self::MAX_BANNED_PLAYERS) + 1;
 }
 
 #[account]
@@ -354,15 +3110,157 @@ pub struct Tournament {
     pub question_count: u8,
     pub category: Option<String>,
     pub difficulty: Option<u8>,
+    pub token_mint: Pubkey,
+    pub crank_bounty: u64,
+    pub update_count: u32,
+    pub min_rating: Option<u32>,
+    pub max_rating: Option<u32>,
+    /// Sum of participants' ratings at registration, for field averages
+    pub rating_sum: u64,
     pub status: TournamentStatus,
     pub created_at: i64,
     pub actual_start_time: Option<i64>,
     pub ended_at: Option<i64>,
+    pub completed_participants: u32,
+    pub open_disputes: u8,
+    pub voided_questions: u64,
+    pub pending_rescores: u32,
+    /// Registrations with saved answers that have not been finalized yet
+    pub pending_finalizations: u32,
+    /// Registration accounts not yet closed
+    pub open_registrations: u32,
+    pub prizes_distributed: u64,
+    /// Weighted category/difficulty buckets; empty means `category`/`difficulty`
+    pub question_mix: Vec<QuestionMixEntry>,
+    /// Question bank ids chosen by `select_questions`
+    pub question_ids: Vec<u64>,
+    /// Participants still owed a refund after cancellation
+    pub pending_refunds: u32,
+    pub organizer_slashed: bool,
+    pub sponsor: Option<SponsorInfo>,
     pub bump: u8,
 }
 
 impl Tournament {
-    pub const SPACE: usize = 8 + 32 + 100 + 500 + 8 + 8 + 4 + 4 + 8 + 8 + 1 + 51 + 2 + 1 + 8 + 9 + 9 + 1;
+    pub const MAX_QUESTIONS: usize = 50;
+    pub const MAX_MIX_ENTRIES: usize = 5;
+    /// After this long, a finished tournament can be closed even if some
+    /// participants never closed their registration
+    pub const CLOSE_EXPIRY: i64 = 180 * 24 * 60 * 60;
+    pub const SPACE: usize = 8 + 32 + (4 + 100) + (4 + 500) + 8 + 8 + 4 + 4 + 8 + 8 + 1 + (1 + 4 + 50) + 2 + 32 + 8 + 4 + 5 + 5 + 8 + 1 + 8 + 9 + 9 + 4 + 1 + 8 + 4 + 4 + 4 + 8
+        + (4 + QuestionMixEntry::SPACE * Self::MAX_MIX_ENTRIES)
+        + (4 + 8 * Self::MAX_QUESTIONS)
+        + 4 + 1
+        + (1 + SponsorInfo::SPACE)
+        + 1;
+}
+
+/// Sponsor branding and escrow details of a free tournament
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub struct SponsorInfo {
+    pub sponsor: Pubkey,
+    pub name: String,
+    pub uri: String,
+    /// Hash of the logo image served from `uri`
+    pub logo_hash: [u8; 32],
+    /// Entrants must hold at least `required_amount` of this mint
+    pub required_mint: Option<Pubkey>,
+    pub required_amount: u64,
+    pub funded_amount: u64,
+    pub reclaimed: bool,
+}
+
+impl SponsorInfo {
+    pub const MAX_NAME_LEN: usize = 50;
+    pub const MAX_URI_LEN: usize = 200;
+    pub const SPACE: usize = 32 + (4 + Self::MAX_NAME_LEN) + (4 + Self::MAX_URI_LEN) + 32 + 33 + 8 + 8 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SponsorshipData {
+    pub name: String,
+    pub uri: String,
+    pub logo_hash: [u8; 32],
+    pub prize_amount: u64,
+    pub required_mint: Option<Pubkey>,
+    pub required_amount: u64,
+}
+
+/// One bucket of a tournament's question mix
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub struct QuestionMixEntry {
+    /// Any category when `None`
+    pub category: Option<String>,
+    /// Any difficulty when `None`
+    pub difficulty: Option<u8>,
+    pub weight_bps: u16,
+}
+
+impl QuestionMixEntry {
+    pub const MAX_CATEGORY_LEN: usize = 50;
+    pub const SPACE: usize = (1 + 4 + Self::MAX_CATEGORY_LEN) + 2 + 2;
+}
+
+/// Always-open tournament where each player plays their own timed attempts
+#[account]
+pub struct PracticeTournament {
+    pub id: u64,
+    pub organizer: Pubkey,
+    pub name: String,
+    /// Length of each personal attempt in seconds
+    pub duration: i64,
+    pub question_count: u8,
+    pub category: Option<String>,
+    pub difficulty: Option<u8>,
+    /// Approved questions matching the filter that attempts draw from
+    pub question_pool: Vec<u64>,
+    pub total_attempts: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl PracticeTournament {
+    pub const MAX_QUESTION_POOL: usize = 200;
+    pub const SPACE: usize = 8 + 32 + (4 + 100) + 8 + 1 + (1 + 4 + 50) + 2 + (4 + 8 * Self::MAX_QUESTION_POOL) + 8 + 8 + 1;
+}
+
+/// A player's attempts at one practice tournament
+#[account]
+pub struct PracticeAttempt {
+    pub player: Pubkey,
+    pub practice_tournament: Pubkey,
+    pub attempt_count: u32,
+    /// Start of the attempt in progress, if any
+    pub started_at: Option<i64>,
+    /// Question ids drawn for the latest attempt
+    pub question_ids: Vec<u64>,
+    pub last_score: u32,
+    pub best_score: u32,
+    pub best_submission_time: Option<i64>,
+    pub bump: u8,
+}
+
+impl PracticeAttempt {
+    pub const SPACE: usize = 32 + 32 + 4 + 9 + (4 + 8 * Tournament::MAX_QUESTIONS) + 4 + 4 + 9 + 1;
+}
+
+/// Summary of a closed tournament
+#[account]
+pub struct TournamentArchive {
+    pub tournament_id: u64,
+    pub tournament: Pubkey,
+    pub organizer: Pubkey,
+    pub winner: Option<Pubkey>,
+    pub prizes_distributed: u64,
+    pub participants: u32,
+    pub status: TournamentStatus,
+    pub ended_at: Option<i64>,
+    pub closed_at: i64,
+    pub bump: u8,
+}
+
+impl TournamentArchive {
+    pub const SPACE: usize = 8 + 32 + 32 + 33 + 8 + 4 + 1 + 9 + 8 + 1;
 }
 
 #[account]
@@ -373,11 +3271,179 @@ pub struct Registration {
     pub score: u32,
     pub completed: bool,
     pub submission_time: Option<i64>,
+    pub entry_fee_paid: u64,
+    pub correct_answers: u64,
+    pub scored_voided_questions: u64,
+    pub disqualified: bool,
+    pub disqualification_reason: u8,
+    pub rating_at_registration: u32,
+    pub rating_applied: bool,
+    /// Answers by question index, valid where `saved_questions` is set,
+    /// encoded as `question_bank::check_answer` expects
+    pub answers: Vec<i64>,
+    pub saved_questions: u64,
+    pub last_saved_at: Option<i64>,
+    pub refunded: bool,
     pub bump: u8,
 }
 
 impl Registration {
-    pub const SPACE: usize = 32 + 8 + 8 + 4 + 1 + 9 + 1;
+    pub const SPACE: usize = 32 + 8 + 8 + 4 + 1 + 9 + 8 + 8 + 8 + 1 + 1 + 4 + 1 + (4 + 8 * Tournament::MAX_QUESTIONS) + 8 + 9 + 1 + 1;
+}
+
+/// Bonded organizer record, required to create tournaments
+#[account]
+pub struct OrganizerProfile {
+    pub organizer: Pubkey,
+    /// Lamports held by this account on top of rent
+    pub bond: u64,
+    /// Set by the manager authority
+    pub verified: bool,
+    pub tournaments_created: u32,
+    pub tournaments_cancelled: u32,
+    pub tournaments_settled_on_time: u32,
+    pub tournaments_slashed: u32,
+    /// Tournaments neither settled nor cancelled; bond is locked while non-zero
+    pub active_tournaments: u32,
+    pub total_slashed: u64,
+    pub registered_at: i64,
+    pub bump: u8,
+}
+
+impl OrganizerProfile {
+    pub const MIN_BOND: u64 = 1_000_000_000;
+    /// Share of the bond slashed per offence
+    pub const SLASH_BPS: u64 = 5_000;
+    /// Time after cancellation to refund every participant
+    pub const REFUND_DEADLINE: i64 = 7 * 24 * 60 * 60;
+    /// Time after the dispute window to settle an ended tournament
+    pub const SETTLEMENT_DEADLINE: i64 = 7 * 24 * 60 * 60;
+    pub const SPACE: usize = 32 + 8 + 1 + 4 + 4 + 4 + 4 + 4 + 8 + 8 + 1;
+}
+
+/// Ephemeral key allowed to submit gameplay instructions for one registration
+#[account]
+pub struct GameSession {
+    pub participant: Pubkey,
+    pub registration: Pubkey,
+    pub session_key: Pubkey,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl GameSession {
+    pub const MAX_DURATION: i64 = 24 * 60 * 60;
+    pub const SPACE: usize = 32 + 32 + 32 + 8 + 8 + 1;
+}
+
+/// Cross-tournament statistics for a player, created on first registration
+#[account]
+pub struct PlayerProfile {
+    pub player: Pubkey,
+    pub tournaments_entered: u32,
+    pub tournaments_completed: u32,
+    pub tournaments_won: u32,
+    pub total_score: u64,
+    pub total_winnings: u64,
+    /// Best final placement (1 = first); 0 until the player places in a payout
+    pub best_rank: u32,
+    /// Elo skill rating
+    pub rating: u32,
+    pub bump: u8,
+}
+
+impl PlayerProfile {
+    pub const INITIAL_RATING: u32 = 1500;
+    pub const MIN_RATING: u32 = 100;
+    pub const SPACE: usize = 32 + 4 + 4 + 4 + 8 + 8 + 4 + 4 + 1;
+}
+
+/// Top finishers of a tournament, best first. Ties on score go to the
+/// earlier submission. Only the top `MAX_ENTRIES` are kept on-chain.
+#[account]
+pub struct Leaderboard {
+    pub tournament: Pubkey,
+    pub entries: Vec<LeaderboardEntry>,
+    pub bump: u8,
+}
+
+impl Leaderboard {
+    pub const MAX_ENTRIES: usize = 10;
+    pub const SPACE: usize = 32 + (4 + LeaderboardEntry::SPACE * Self::MAX_ENTRIES) + 1;
+
+    /// Insert or replace a participant's entry, keeping the table sorted
+    pub fn record(&mut self, entry: LeaderboardEntry) {
+        self.remove(&entry.participant);
+
+        let pos = self.entries
+            .iter()
+            .position(|e| {
+                entry.score > e.score
+                    || (entry.score == e.score && entry.submission_time < e.submission_time)
+            })
+            .unwrap_or(self.entries.len());
+        self.entries.insert(pos, entry);
+        self.entries.truncate(Self::MAX_ENTRIES);
+    }
+
+    /// Drop a participant's entry if present
+    pub fn remove(&mut self, participant: &Pubkey) {
+        self.entries.retain(|e| e.participant != *participant);
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub struct LeaderboardEntry {
+    pub participant: Pubkey,
+    pub score: u32,
+    pub submission_time: i64,
+}
+
+impl LeaderboardEntry {
+    pub const SPACE: usize = 32 + 4 + 8;
+}
+
+/// Fields the organizer may change before a tournament starts.
+/// `None` leaves a field unchanged; for the question filters `Some(None)`
+/// clears the filter.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateTournamentData {
+    pub description: Option<String>,
+    pub start_time: Option<i64>,
+    pub max_participants: Option<u32>,
+    pub entry_fee: Option<u64>,
+    pub category: Option<Option<String>>,
+    pub difficulty: Option<Option<u8>>,
+}
+
+/// A bonded challenge against one question of an ended tournament. Each
+/// participant can hold one open dispute per question
+#[account]
+pub struct Dispute {
+    pub tournament: Pubkey,
+    pub disputer: Pubkey,
+    pub question_index: u8,
+    pub bond: u64,
+    pub status: DisputeStatus,
+    pub created_at: i64,
+    pub resolved_at: Option<i64>,
+    pub resolver: Option<Pubkey>,
+    pub bump: u8,
+}
+
+impl Dispute {
+    /// How long after `end_tournament` disputes can be opened
+    pub const WINDOW_SECONDS: i64 = 24 * 60 * 60;
+    pub const BOND_LAMPORTS: u64 = 50_000_000;
+    pub const SPACE: usize = 32 + 32 + 1 + 8 + 1 + 8 + 9 + 33 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub enum DisputeStatus {
+    Open,
+    Upheld,
+    Rejected,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
@@ -385,6 +3451,7 @@ pub enum TournamentStatus {
     Registration,
     Active,
     Ended,
+    Settled,
     Cancelled,
 }
 
@@ -394,6 +3461,8 @@ pub enum TournamentError {
     NameTooLong,
     #[msg("Tournament description is too long (max 500 characters)")]
     DescriptionTooLong,
+    #[msg("Tournament category is too long (max 50 characters)")]
+    CategoryTooLong,
     #[msg("Invalid maximum participants count")]
     InvalidMaxParticipants,
     #[msg("Invalid start time")]
@@ -428,4 +3497,134 @@ pub enum TournamentError {
     InvalidPrizeData,
     #[msg("Insufficient prize pool")]
     InsufficientPrizePool,
+    #[msg("Token mint does not match the tournament mint")]
+    InvalidTokenMint,
+    #[msg("Token mint uses an extension that tournaments do not support")]
+    UnsupportedMintExtension,
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+    #[msg("Unauthorized authority")]
+    UnauthorizedAuthority,
+    #[msg("Only the organizer or a moderator can perform this action")]
+    UnauthorizedModerator,
+    #[msg("Moderator already exists")]
+    ModeratorAlreadyExists,
+    #[msg("Moderator not found")]
+    ModeratorNotFound,
+    #[msg("Too many moderators")]
+    TooManyModerators,
+    #[msg("Participant has been disqualified")]
+    ParticipantDisqualified,
+    #[msg("Participant token account is required to refund the entry fee")]
+    MissingRefundAccount,
+    #[msg("Player is banned from tournaments")]
+    PlayerBanned,
+    #[msg("Player is already banned")]
+    PlayerAlreadyBanned,
+    #[msg("Player is not banned")]
+    PlayerNotBanned,
+    #[msg("Ban list is full")]
+    BanListFull,
+    #[msg("Dispute window has closed")]
+    DisputeWindowClosed,
+    #[msg("Dispute window is still open")]
+    DisputeWindowOpen,
+    #[msg("Invalid question index")]
+    InvalidQuestionIndex,
+    #[msg("Question has already been voided")]
+    QuestionAlreadyVoided,
+    #[msg("Only question bank curators can resolve disputes")]
+    UnauthorizedCurator,
+    #[msg("Dispute has already been resolved")]
+    DisputeAlreadyResolved,
+    #[msg("Tournament has unresolved disputes")]
+    UnresolvedDisputes,
+    #[msg("Registrations are still waiting to be re-scored")]
+    RescorePending,
+    #[msg("Registration does not need re-scoring")]
+    RegistrationUpToDate,
+    #[msg("Entry fee cannot change once participants have registered")]
+    EntryFeeLocked,
+    #[msg("Player profile does not match the winner")]
+    InvalidPlayerProfile,
+    #[msg("Minimum rating must not exceed maximum rating")]
+    InvalidRatingBracket,
+    #[msg("Player rating is outside the tournament bracket")]
+    RatingOutOfBracket,
+    #[msg("Rating update has already been applied")]
+    RatingAlreadyApplied,
+    #[msg("Winners must match the top of the leaderboard")]
+    WinnerNotOnLeaderboard,
+    #[msg("Session duration must be positive and at most 24 hours")]
+    InvalidSessionDuration,
+    #[msg("Signer is not authorized to play for this registration")]
+    UnauthorizedSessionKey,
+    #[msg("Session key has expired")]
+    SessionExpired,
+    #[msg("No answers have been saved")]
+    NoAnswersSaved,
+    #[msg("Saved answers are waiting to be finalized")]
+    FinalizationPending,
+    #[msg("Rating update must be applied before closing the registration")]
+    RatingUpdatePending,
+    #[msg("Pass either the tournament or its archive record")]
+    InvalidTournamentAccounts,
+    #[msg("Registrations are still open")]
+    OpenRegistrations,
+    #[msg("Organizer token account required to return leftover funds")]
+    MissingOrganizerTokenAccount,
+    #[msg("Not enough questions in the question bank")]
+    NotEnoughQuestions,
+    #[msg("A practice attempt is already in progress")]
+    AttemptInProgress,
+    #[msg("No practice attempt in progress")]
+    NoAttemptInProgress,
+    #[msg("Practice attempt time has run out")]
+    AttemptExpired,
+    #[msg("Question is not approved or does not match the practice filter")]
+    IneligibleQuestion,
+    #[msg("Question is already in the practice pool")]
+    QuestionAlreadyInPool,
+    #[msg("Practice question pool is full")]
+    QuestionPoolFull,
+    #[msg("Question mix must have at most 5 buckets with weights adding up to 10000")]
+    InvalidQuestionMix,
+    #[msg("Question bank cannot satisfy a question mix bucket")]
+    InsufficientQuestionsForBucket,
+    #[msg("Question selected more than once")]
+    DuplicateQuestion,
+    #[msg("Questions must be selected before the tournament starts")]
+    QuestionsNotSelected,
+    #[msg("Organizer bond is below the minimum")]
+    InsufficientOrganizerBond,
+    #[msg("Organizer has tournaments that are not settled or cancelled")]
+    OrganizerHasActiveTournaments,
+    #[msg("Organizer was already slashed for this tournament")]
+    OrganizerAlreadySlashed,
+    #[msg("Organizer cannot be slashed for this tournament")]
+    OrganizerNotSlashable,
+    #[msg("Participant has already been refunded")]
+    AlreadyRefunded,
+    #[msg("Participants are still owed refunds")]
+    RefundPending,
+    #[msg("Sponsored tournaments must be free to enter")]
+    SponsoredTournamentNotFree,
+    #[msg("Tournament already has a sponsor")]
+    AlreadySponsored,
+    #[msg("Sponsor URI too long")]
+    InvalidSponsorUri,
+    #[msg("Tournament has no sponsor")]
+    NotSponsored,
+    #[msg("Only the sponsor can reclaim sponsor funds")]
+    UnauthorizedSponsor,
+    #[msg("Sponsor funds have already been reclaimed")]
+    SponsorFundsReclaimed,
+    #[msg("Sponsor funds must be reclaimed first")]
+    SponsorFundsNotReclaimed,
+    #[msg("Prize pool accounts are required")]
+    MissingPrizePoolAccounts,
+    #[msg("Participant does not meet the sponsor's entry requirement")]
+    SponsorRequirementNotMet,
+    #[msg("Question accounts do not match the selected questions")]
+    InvalidQuestionAccounts,
 }
\ No newline at end of file
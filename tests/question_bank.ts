@@ -3,6 +3,13 @@ import { Program } from "@coral-xyz/anchor";
 import { QuestionBank } from "../target/types/question_bank";
 import { expect } from "chai";
 import { PublicKey, Keypair } from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  createMint,
+  createAccount,
+  mintTo,
+  getAccount,
+} from "@solana/spl-token";
 
 describe("Question Bank", () => {
   // Configure the client to use the local cluster
@@ -26,6 +33,8 @@ describe("Question Bank", () => {
   let user2ReputationBump: number;
   let curatorReputationPda: PublicKey;
   let curatorReputationBump: number;
+  let bankConfigPda: PublicKey;
+  let curationPoolPda: PublicKey;
 
   before(async () => {
     // Generate test keypairs
@@ -51,6 +60,16 @@ describe("Question Bank", () => {
       program.programId
     );
 
+    [bankConfigPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bank_config")],
+      program.programId
+    );
+
+    [curationPoolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("curation_pool")],
+      program.programId
+    );
+
     [user1ReputationPda, user1ReputationBump] = PublicKey.findProgramAddressSync(
       [Buffer.from("reputation"), user1.publicKey.toBuffer()],
       program.programId
@@ -73,6 +92,8 @@ describe("Question Bank", () => {
         .initializeQuestionBank(authority.publicKey)
         .accounts({
           questionBank: questionBankPda,
+          bankConfig: bankConfigPda,
+          curationPool: curationPoolPda,
           payer: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
@@ -96,6 +117,7 @@ describe("Question Bank", () => {
         .initializeUserReputation()
         .accounts({
           userReputation: user1ReputationPda,
+          bankConfig: bankConfigPda,
           user: user1.publicKey,
           payer: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
@@ -108,6 +130,7 @@ describe("Question Bank", () => {
         .initializeUserReputation()
         .accounts({
           userReputation: user2ReputationPda,
+          bankConfig: bankConfigPda,
           user: user2.publicKey,
           payer: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
@@ -120,6 +143,7 @@ describe("Question Bank", () => {
         .initializeUserReputation()
         .accounts({
           userReputation: curatorReputationPda,
+          bankConfig: bankConfigPda,
           user: curator.publicKey,
           payer: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
@@ -214,6 +238,280 @@ describe("Question Bank", () => {
     });
   });
 
+  describe("Reputation Updates", () => {
+    let reputationUser: Keypair;
+    let reputationUserPda: PublicKey;
+
+    before(async () => {
+      reputationUser = Keypair.generate();
+      [reputationUserPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reputation"), reputationUser.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeUserReputation()
+        .accounts({
+          userReputation: reputationUserPda,
+          bankConfig: bankConfigPda,
+          user: reputationUser.publicKey,
+          payer: authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+    });
+
+    it("Allows the authority to update reputation", async () => {
+      await program.methods
+        .updateReputation({ questionApproved: {} })
+        .accounts({
+          userReputation: reputationUserPda,
+          questionBank: questionBankPda,
+          bankConfig: bankConfigPda,
+          caller: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      const reputation = await program.account.userReputation.fetch(reputationUserPda);
+      expect(reputation.questionsApproved).to.equal(1);
+      expect(reputation.reputationScore.toNumber()).to.equal(150);
+    });
+
+    it("Fails to update own reputation without authorization", async () => {
+      try {
+        await program.methods
+          .updateReputation({ questionApproved: {} })
+          .accounts({
+            userReputation: reputationUserPda,
+            questionBank: questionBankPda,
+            bankConfig: bankConfigPda,
+            caller: reputationUser.publicKey,
+          })
+          .signers([reputationUser])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("UnauthorizedReputationCaller");
+      }
+    });
+
+    it("Adds and removes an allowlisted reputation caller", async () => {
+      const callerProgram = Keypair.generate().publicKey;
+
+      await program.methods
+        .addReputationCaller(callerProgram)
+        .accounts({
+          questionBank: questionBankPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      let questionBank = await program.account.questionBank.fetch(questionBankPda);
+      expect(questionBank.reputationCallers.map(c => c.toString())).to.include(callerProgram.toString());
+
+      await program.methods
+        .removeReputationCaller(callerProgram)
+        .accounts({
+          questionBank: questionBankPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      questionBank = await program.account.questionBank.fetch(questionBankPda);
+      expect(questionBank.reputationCallers).to.have.lengthOf(0);
+    });
+
+    it("Fails to add a reputation caller without authority", async () => {
+      try {
+        await program.methods
+          .addReputationCaller(user1.publicKey)
+          .accounts({
+            questionBank: questionBankPda,
+            authority: user1.publicKey,
+          })
+          .signers([user1])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("UnauthorizedAuthority");
+      }
+    });
+  });
+
+  describe("Bank Config", () => {
+    it("Initializes with default thresholds", async () => {
+      const bankConfig = await program.account.bankConfig.fetch(bankConfigPda);
+      expect(bankConfig.voteWeighting).to.deep.equal({ tiered: {} });
+      expect(bankConfig.weightedQuorum.toNumber()).to.equal(5);
+      expect(bankConfig.voteTiers.map((tier) => tier.toNumber())).to.deep.equal([200, 500, 1000]);
+      expect(bankConfig.minSubmitReputation.toNumber()).to.equal(100);
+      expect(bankConfig.initialReputation.toNumber()).to.equal(100);
+      expect(bankConfig.minVotes).to.equal(5);
+      expect(bankConfig.votingPeriod.toNumber()).to.equal(7 * 24 * 60 * 60);
+      expect(bankConfig.voteReward.toNumber()).to.equal(10);
+      expect(bankConfig.maxQuestionLen).to.equal(500);
+      expect(bankConfig.minDifficulty).to.equal(1);
+      expect(bankConfig.maxDifficulty).to.equal(3);
+      expect(bankConfig.submissionBond.toNumber()).to.equal(10_000_000);
+      expect(bankConfig.maxPendingQuestions).to.equal(5);
+      expect(bankConfig.flagBond.toNumber()).to.equal(50_000_000);
+      expect(bankConfig.minReviewVotes).to.equal(3);
+    });
+
+    it("Allows the authority to update the config", async () => {
+      await program.methods
+        .updateBankConfig({
+          ...emptyConfigUpdate(),
+          voteWeighting: { linear: {} },
+          weightedQuorum: new anchor.BN(500),
+          voteTiers: [new anchor.BN(300), new anchor.BN(600), new anchor.BN(1200)],
+          maxDifficulty: 5,
+        })
+        .accounts({
+          questionBank: questionBankPda,
+          bankConfig: bankConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      let bankConfig = await program.account.bankConfig.fetch(bankConfigPda);
+      expect(bankConfig.voteWeighting).to.deep.equal({ linear: {} });
+      expect(bankConfig.weightedQuorum.toNumber()).to.equal(500);
+      expect(bankConfig.voteTiers.map((tier) => tier.toNumber())).to.deep.equal([300, 600, 1200]);
+      expect(bankConfig.maxDifficulty).to.equal(5);
+      // Unset fields are left unchanged
+      expect(bankConfig.minVotes).to.equal(5);
+
+      // The voting tests below use fresh accounts, which weigh nothing under tiers
+      await program.methods
+        .updateBankConfig({
+          ...emptyConfigUpdate(),
+          voteWeighting: { squareRoot: {} },
+          weightedQuorum: new anchor.BN(50),
+          voteTiers: [new anchor.BN(200), new anchor.BN(500), new anchor.BN(1000)],
+          maxDifficulty: 3,
+        })
+        .accounts({
+          questionBank: questionBankPda,
+          bankConfig: bankConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      bankConfig = await program.account.bankConfig.fetch(bankConfigPda);
+      expect(bankConfig.voteWeighting).to.deep.equal({ squareRoot: {} });
+      expect(bankConfig.maxDifficulty).to.equal(3);
+    });
+
+    it("Rejects out-of-bounds values", async () => {
+      try {
+        await program.methods
+          .updateBankConfig({
+            ...emptyConfigUpdate(),
+            minDifficulty: 4,
+          })
+          .accounts({
+            questionBank: questionBankPda,
+            bankConfig: bankConfigPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("InvalidConfig");
+      }
+
+      try {
+        await program.methods
+          .updateBankConfig({
+            ...emptyConfigUpdate(),
+            maxQuestionLen: 501,
+          })
+          .accounts({
+            questionBank: questionBankPda,
+            bankConfig: bankConfigPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("InvalidConfig");
+      }
+
+      try {
+        // Tier thresholds must be ascending
+        await program.methods
+          .updateBankConfig({
+            ...emptyConfigUpdate(),
+            voteTiers: [new anchor.BN(500), new anchor.BN(200), new anchor.BN(1000)],
+          })
+          .accounts({
+            questionBank: questionBankPda,
+            bankConfig: bankConfigPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("InvalidConfig");
+      }
+
+      try {
+        await program.methods
+          .updateBankConfig({
+            ...emptyConfigUpdate(),
+            votingPeriod: new anchor.BN(60), // below the 1 hour minimum
+          })
+          .accounts({
+            questionBank: questionBankPda,
+            bankConfig: bankConfigPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("InvalidConfig");
+      }
+    });
+
+    it("Fails to update the config without authority", async () => {
+      try {
+        await program.methods
+          .updateBankConfig({
+            ...emptyConfigUpdate(),
+            voteWeighting: { linear: {} },
+          })
+          .accounts({
+            questionBank: questionBankPda,
+            bankConfig: bankConfigPda,
+            authority: user1.publicKey,
+          })
+          .signers([user1])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("UnauthorizedAuthority");
+      }
+    });
+  });
+
   describe("Question Submission", () => {
     let questionPda: PublicKey;
     let questionBump: number;
@@ -227,7 +525,7 @@ describe("Question Bank", () => {
       const questionData = {
         questionText: "What is the capital of France?",
         options: ["London", "Berlin", "Paris", "Madrid"],
-        correctAnswer: 2,
+        questionType: { multipleChoice: { correctIndex: 2 } },
         category: "Geography",
         difficulty: 2,
       };
@@ -237,6 +535,7 @@ describe("Question Bank", () => {
         .accounts({
           question: questionPda,
           questionBank: questionBankPda,
+          bankConfig: bankConfigPda,
           userReputation: user1ReputationPda,
           submitter: user1.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
@@ -250,21 +549,27 @@ describe("Question Bank", () => {
       expect(question.submitter.toString()).to.equal(user1.publicKey.toString());
       expect(question.questionText).to.equal("What is the capital of France?");
       expect(question.options).to.deep.equal(["London", "Berlin", "Paris", "Madrid"]);
-      expect(question.correctAnswer).to.equal(2);
+      expect(question.questionType).to.deep.equal({ multipleChoice: { correctIndex: 2 } });
       expect(question.category).to.equal("Geography");
       expect(question.difficulty).to.equal(2);
       expect(question.votesApprove).to.equal(0);
       expect(question.votesReject).to.equal(0);
-      expect(question.voters).to.have.lengthOf(0);
       expect(question.status).to.deep.equal({ pending: {} });
+      expect(question.votingDeadline.toNumber()).to.equal(
+        question.createdAt.toNumber() + 7 * 24 * 60 * 60
+      );
 
       // Verify question bank updated
       const questionBank = await program.account.questionBank.fetch(questionBankPda);
       expect(questionBank.totalQuestions.toNumber()).to.equal(1);
 
+      // Verify the submission bond is escrowed in the question account
+      expect(question.bond.toNumber()).to.equal(10_000_000);
+
       // Verify user reputation updated
       const userReputation = await program.account.userReputation.fetch(user1ReputationPda);
       expect(userReputation.questionsSubmitted).to.equal(1);
+      expect(userReputation.pendingQuestions).to.equal(1);
     });
 
     it("Fails to submit question with insufficient reputation", async () => {
@@ -283,6 +588,7 @@ describe("Question Bank", () => {
         .initializeUserReputation()
         .accounts({
           userReputation: newUserReputationPda,
+          bankConfig: bankConfigPda,
           user: newUser.publicKey,
           payer: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
@@ -310,6 +616,7 @@ describe("Question Bank", () => {
         .initializeUserReputation()
         .accounts({
           userReputation: lowRepUserPda,
+          bankConfig: bankConfigPda,
           user: lowRepUser.publicKey,
           payer: authority.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
@@ -321,6 +628,65 @@ describe("Question Bank", () => {
       // For testing purposes, we'll verify the current behavior with sufficient reputation
     });
 
+    it("Fails to submit past the pending question limit", async () => {
+      await program.methods
+        .updateBankConfig({
+          ...emptyConfigUpdate(),
+          maxPendingQuestions: 1,
+        })
+        .accounts({
+          questionBank: questionBankPda,
+          bankConfig: bankConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      const [nextQuestionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("question"), Buffer.from([1, 0, 0, 0, 0, 0, 0, 0])],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .submitQuestion({
+            questionText: "What is the largest ocean?",
+            options: ["Atlantic", "Indian", "Arctic", "Pacific"],
+            questionType: { multipleChoice: { correctIndex: 3 } },
+            category: "Geography",
+            difficulty: 1,
+          })
+          .accounts({
+            question: nextQuestionPda,
+            questionBank: questionBankPda,
+            bankConfig: bankConfigPda,
+            userReputation: user1ReputationPda,
+            submitter: user1.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("TooManyPendingQuestions");
+      }
+
+      // Restore the default for later tests
+      await program.methods
+        .updateBankConfig({
+          ...emptyConfigUpdate(),
+          maxPendingQuestions: 5,
+        })
+        .accounts({
+          questionBank: questionBankPda,
+          bankConfig: bankConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+    });
+
     it("Fails to submit question with invalid format", async () => {
       const [nextQuestionPda] = PublicKey.findProgramAddressSync(
         [Buffer.from("question"), Buffer.from([1, 0, 0, 0, 0, 0, 0, 0])], // question ID 1
@@ -330,7 +696,7 @@ describe("Question Bank", () => {
       const invalidQuestionData = {
         questionText: "A".repeat(501), // Exceeds 500 character limit
         options: ["A", "B", "C", "D"],
-        correctAnswer: 0,
+        questionType: { multipleChoice: { correctIndex: 0 } },
         category: "Test",
         difficulty: 1,
       };
@@ -341,6 +707,7 @@ describe("Question Bank", () => {
           .accounts({
             question: nextQuestionPda,
             questionBank: questionBankPda,
+            bankConfig: bankConfigPda,
             userReputation: user1ReputationPda,
             submitter: user1.publicKey,
             systemProgram: anchor.web3.SystemProgram.programId,
@@ -362,13 +729,21 @@ describe("Question Bank", () => {
         program.programId
       );
 
+      const [voteRecordPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), questionPda.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+
       // User2 votes to approve
       await program.methods
         .voteOnQuestion({ approve: {} })
         .accounts({
           question: questionPda,
+          voteRecord: voteRecordPda,
+          bankConfig: bankConfigPda,
           userReputation: user2ReputationPda,
           voter: user2.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([user2])
         .rpc();
@@ -377,8 +752,13 @@ describe("Question Bank", () => {
       const question = await program.account.question.fetch(questionPda);
       expect(question.votesApprove).to.equal(1);
       expect(question.votesReject).to.equal(0);
-      expect(question.voters).to.have.lengthOf(1);
-      expect(question.voters[0].toString()).to.equal(user2.publicKey.toString());
+
+      const voteRecord = await program.account.voteRecord.fetch(voteRecordPda);
+      expect(voteRecord.question.toString()).to.equal(questionPda.toString());
+      expect(voteRecord.voter.toString()).to.equal(user2.publicKey.toString());
+      expect(voteRecord.voteType).to.deep.equal({ approve: {} });
+      expect(voteRecord.weight.toNumber()).to.equal(10); // sqrt(100) under square-root weighting
+      expect(question.weightApprove.toNumber()).to.equal(10);
 
       // Verify voter reputation updated
       const user2Reputation = await program.account.userReputation.fetch(user2ReputationPda);
@@ -392,20 +772,29 @@ describe("Question Bank", () => {
         program.programId
       );
 
+      const [voteRecordPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), questionPda.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+
       try {
         await program.methods
           .voteOnQuestion({ reject: {} })
           .accounts({
             question: questionPda,
+            voteRecord: voteRecordPda,
+            bankConfig: bankConfigPda,
             userReputation: user2ReputationPda,
             voter: user2.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
           })
           .signers([user2])
           .rpc();
         
         expect.fail("Should have failed");
       } catch (error) {
-        expect(error.error.errorCode.code).to.equal("AlreadyVoted");
+        // The vote record already exists, so its `init` fails
+        expect(error.logs.join("\n")).to.include("already in use");
       }
     });
 
@@ -415,13 +804,21 @@ describe("Question Bank", () => {
         program.programId
       );
 
+      const [voteRecordPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), questionPda.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
       try {
         await program.methods
           .voteOnQuestion({ approve: {} })
           .accounts({
             question: questionPda,
+            voteRecord: voteRecordPda,
+            bankConfig: bankConfigPda,
             userReputation: user1ReputationPda,
             voter: user1.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
           })
           .signers([user1])
           .rpc();
@@ -455,6 +852,7 @@ describe("Question Bank", () => {
           .initializeUserReputation()
           .accounts({
             userReputation: voterReputationPda,
+            bankConfig: bankConfigPda,
             user: voter.publicKey,
             payer: authority.publicKey,
             systemProgram: anchor.web3.SystemProgram.programId,
@@ -467,13 +865,21 @@ describe("Question Bank", () => {
 
         // Cast votes (3 approve, 1 reject for majority approval)
         const voteType = i < 3 ? { approve: {} } : { reject: {} };
+
+        const [voteRecordPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("vote"), questionPda.toBuffer(), voter.publicKey.toBuffer()],
+          program.programId
+        );
         
         await program.methods
           .voteOnQuestion(voteType)
           .accounts({
             question: questionPda,
+            voteRecord: voteRecordPda,
+            bankConfig: bankConfigPda,
             userReputation: voterReputationPda,
             voter: voter.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
           })
           .signers([voter])
           .rpc();
@@ -483,7 +889,8 @@ describe("Question Bank", () => {
       const question = await program.account.question.fetch(questionPda);
       expect(question.votesApprove).to.equal(4); // 1 from user2 + 3 from new voters
       expect(question.votesReject).to.equal(1); // 1 from last voter
-      expect(question.voters).to.have.lengthOf(5);
+      expect(question.weightApprove.toNumber()).to.equal(40);
+      expect(question.weightReject.toNumber()).to.equal(10);
     });
   });
 
@@ -494,12 +901,17 @@ describe("Question Bank", () => {
         program.programId
       );
 
+      const submitterBalanceBefore = await provider.connection.getBalance(user1.publicKey);
+
       await program.methods
         .finalizeQuestion(new anchor.BN(0))
         .accounts({
           question: questionPda,
           questionBank: questionBankPda,
+          bankConfig: bankConfigPda,
           submitterReputation: user1ReputationPda,
+          curationPool: curationPoolPda,
+          submitter: user1.publicKey,
           curator: curator.publicKey,
         })
         .signers([curator])
@@ -517,6 +929,12 @@ describe("Question Bank", () => {
       const user1Reputation = await program.account.userReputation.fetch(user1ReputationPda);
       expect(user1Reputation.questionsApproved).to.equal(1);
       expect(user1Reputation.reputationScore.toNumber()).to.equal(150); // 100 + 50 for approved question
+      expect(user1Reputation.pendingQuestions).to.equal(0);
+
+      // Verify the bond was refunded on approval
+      expect(question.bond.toNumber()).to.equal(0);
+      const submitterBalanceAfter = await provider.connection.getBalance(user1.publicKey);
+      expect(submitterBalanceAfter - submitterBalanceBefore).to.equal(10_000_000);
     });
 
     it("Fails to finalize question without curator privileges", async () => {
@@ -529,7 +947,7 @@ describe("Question Bank", () => {
       const questionData = {
         questionText: "What is 2 + 2?",
         options: ["3", "4", "5", "6"],
-        correctAnswer: 1,
+        questionType: { multipleChoice: { correctIndex: 1 } },
         category: "Math",
         difficulty: 1,
       };
@@ -539,6 +957,7 @@ describe("Question Bank", () => {
         .accounts({
           question: nextQuestionPda,
           questionBank: questionBankPda,
+          bankConfig: bankConfigPda,
           userReputation: user1ReputationPda,
           submitter: user1.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
@@ -553,7 +972,10 @@ describe("Question Bank", () => {
           .accounts({
             question: nextQuestionPda,
             questionBank: questionBankPda,
+            bankConfig: bankConfigPda,
             submitterReputation: user1ReputationPda,
+            curationPool: curationPoolPda,
+            submitter: user1.publicKey,
             curator: user2.publicKey, // user2 is not a curator
           })
           .signers([user2])
@@ -566,31 +988,156 @@ describe("Question Bank", () => {
     });
   });
 
-  describe("Get Approved Questions", () => {
-    it("Returns approved questions", async () => {
-      const result = await program.methods
-        .getApprovedQuestions("Geography", 2)
-        .accounts({
-          questionBank: questionBankPda,
-        })
-        .view();
-
-      expect(result).to.be.an('array');
-      expect(result.length).to.be.greaterThan(0);
-    });
-  });
+  describe("Question Editing", () => {
+    let editedQuestionPda: PublicKey;
+    let voteRecordPda: PublicKey;
 
-  describe("Edge Cases and Error Handling", () => {
-    it("Handles question with maximum character limits", async () => {
-      const [maxQuestionPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("question"), Buffer.from([2, 0, 0, 0, 0, 0, 0, 0])],
+    before(async () => {
+      // Question 1 is still pending after the finalization tests
+      [editedQuestionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("question"), Buffer.from([1, 0, 0, 0, 0, 0, 0, 0])],
+        program.programId
+      );
+      [voteRecordPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), editedQuestionPda.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .voteOnQuestion({ approve: {} })
+        .accounts({
+          question: editedQuestionPda,
+          voteRecord: voteRecordPda,
+          bankConfig: bankConfigPda,
+          userReputation: user2ReputationPda,
+          voter: user2.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+    });
+
+    const editedData = {
+      questionText: "What is 2 + 3?",
+      options: ["4", "5", "6", "7"],
+      questionType: { multipleChoice: { correctIndex: 1 } },
+      category: "Math",
+      difficulty: 1,
+    };
+
+    it("Fails to edit another user's question", async () => {
+      try {
+        await program.methods
+          .editQuestion(editedData)
+          .accounts({
+            question: editedQuestionPda,
+            bankConfig: bankConfigPda,
+            submitter: user2.publicKey,
+          })
+          .signers([user2])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("NotQuestionSubmitter");
+      }
+    });
+
+    it("Edits a pending question and resets its votes", async () => {
+      let question = await program.account.question.fetch(editedQuestionPda);
+      expect(question.revision).to.equal(0);
+      expect(question.votesApprove).to.equal(1);
+      const originalDeadline = question.votingDeadline.toNumber();
+
+      await program.methods
+        .editQuestion(editedData)
+        .accounts({
+          question: editedQuestionPda,
+          bankConfig: bankConfigPda,
+          submitter: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      question = await program.account.question.fetch(editedQuestionPda);
+      expect(question.revision).to.equal(1);
+      expect(question.questionText).to.equal("What is 2 + 3?");
+      expect(question.previousRevisionHash).to.not.deep.equal(new Array(32).fill(0));
+      expect(question.votesApprove).to.equal(0);
+      expect(question.weightApprove.toNumber()).to.equal(0);
+      // Edited days before the deadline, so the re-vote window fits inside it
+      expect(question.votingDeadline.toNumber()).to.equal(originalDeadline);
+      expect(question.status).to.deep.equal({ pending: {} });
+    });
+
+    it("Lets a stale voter re-vote on the new revision", async () => {
+      await program.methods
+        .revoteOnQuestion({ reject: {} })
+        .accounts({
+          question: editedQuestionPda,
+          voteRecord: voteRecordPda,
+          bankConfig: bankConfigPda,
+          userReputation: user2ReputationPda,
+          voter: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      const voteRecord = await program.account.voteRecord.fetch(voteRecordPda);
+      expect(voteRecord.revision).to.equal(1);
+      expect(voteRecord.voteType).to.deep.equal({ reject: {} });
+
+      const question = await program.account.question.fetch(editedQuestionPda);
+      expect(question.votesReject).to.equal(1);
+      expect(question.votesApprove).to.equal(0);
+    });
+
+    it("Fails to re-vote on the current revision", async () => {
+      try {
+        await program.methods
+          .revoteOnQuestion({ approve: {} })
+          .accounts({
+            question: editedQuestionPda,
+            voteRecord: voteRecordPda,
+            bankConfig: bankConfigPda,
+            userReputation: user2ReputationPda,
+            voter: user2.publicKey,
+          })
+          .signers([user2])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("VoteNotStale");
+      }
+    });
+  });
+
+  describe("Get Approved Questions", () => {
+    it("Returns approved questions", async () => {
+      const result = await program.methods
+        .getApprovedQuestions("Geography", 2)
+        .accounts({
+          questionBank: questionBankPda,
+        })
+        .view();
+
+      expect(result).to.be.an('array');
+      expect(result.length).to.be.greaterThan(0);
+    });
+  });
+
+  describe("Edge Cases and Error Handling", () => {
+    it("Handles question with maximum character limits", async () => {
+      const [maxQuestionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("question"), Buffer.from([2, 0, 0, 0, 0, 0, 0, 0])],
         program.programId
       );
 
       const maxQuestionData = {
         questionText: "A".repeat(500), // Maximum allowed
         options: ["A".repeat(100), "B".repeat(100), "C".repeat(100), "D".repeat(100)], // Maximum allowed
-        correctAnswer: 0,
+        questionType: { multipleChoice: { correctIndex: 0 } },
         category: "A".repeat(50), // Maximum allowed
         difficulty: 3,
       };
@@ -600,6 +1147,7 @@ describe("Question Bank", () => {
         .accounts({
           question: maxQuestionPda,
           questionBank: questionBankPda,
+          bankConfig: bankConfigPda,
           userReputation: user1ReputationPda,
           submitter: user1.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
@@ -621,7 +1169,7 @@ describe("Question Bank", () => {
       const invalidDifficultyData = {
         questionText: "Test question",
         options: ["A", "B", "C", "D"],
-        correctAnswer: 0,
+        questionType: { multipleChoice: { correctIndex: 0 } },
         category: "Test",
         difficulty: 4, // Invalid difficulty (max is 3)
       };
@@ -632,6 +1180,7 @@ describe("Question Bank", () => {
           .accounts({
             question: invalidQuestionPda,
             questionBank: questionBankPda,
+            bankConfig: bankConfigPda,
             userReputation: user1ReputationPda,
             submitter: user1.publicKey,
             systemProgram: anchor.web3.SystemProgram.programId,
@@ -654,7 +1203,7 @@ describe("Question Bank", () => {
       const invalidAnswerData = {
         questionText: "Test question",
         options: ["A", "B", "C", "D"],
-        correctAnswer: 4, // Invalid (only 0-3 are valid)
+        questionType: { multipleChoice: { correctIndex: 4 } }, // Invalid (only 0-3 are valid)
         category: "Test",
         difficulty: 1,
       };
@@ -665,6 +1214,7 @@ describe("Question Bank", () => {
           .accounts({
             question: invalidQuestionPda,
             questionBank: questionBankPda,
+            bankConfig: bankConfigPda,
             userReputation: user1ReputationPda,
             submitter: user1.publicKey,
             systemProgram: anchor.web3.SystemProgram.programId,
@@ -678,4 +1228,543 @@ describe("Question Bank", () => {
       }
     });
   });
-});
\ No newline at end of file
+
+  describe("Question Types", () => {
+    const nextQuestionPda = async () => {
+      const questionBank = await program.account.questionBank.fetch(questionBankPda);
+      return PublicKey.findProgramAddressSync(
+        [Buffer.from("question"), questionBank.totalQuestions.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0];
+    };
+
+    const submit = async (questionData: any) => {
+      const questionPda = await nextQuestionPda();
+      await program.methods
+        .submitQuestion(questionData)
+        .accounts({
+          question: questionPda,
+          questionBank: questionBankPda,
+          bankConfig: bankConfigPda,
+          userReputation: user2ReputationPda,
+          submitter: user2.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+      return questionPda;
+    };
+
+    it("Submits a true/false question", async () => {
+      const questionPda = await submit({
+        questionText: "The Pacific is the largest ocean.",
+        options: [],
+        questionType: { trueFalse: { answer: true } },
+        category: "Geography",
+        difficulty: 1,
+      });
+
+      const question = await program.account.question.fetch(questionPda);
+      expect(question.options).to.have.lengthOf(0);
+      expect(question.questionType).to.deep.equal({ trueFalse: { answer: true } });
+    });
+
+    it("Submits a numeric question with a tolerance", async () => {
+      const questionPda = await submit({
+        questionText: "In what year did Apollo 11 land on the Moon?",
+        options: [],
+        questionType: { numeric: { answer: new anchor.BN(1969), tolerance: new anchor.BN(0) } },
+        category: "History",
+        difficulty: 2,
+      });
+
+      const question = await program.account.question.fetch(questionPda);
+      expect(question.questionType.numeric.answer.toNumber()).to.equal(1969);
+    });
+
+    it("Submits a six-option multi-select question", async () => {
+      const questionPda = await submit({
+        questionText: "Which of these are primes?",
+        options: ["2", "4", "5", "9", "11", "15"],
+        questionType: { multiSelect: { correctMask: 0b010101 } },
+        category: "Math",
+        difficulty: 2,
+      });
+
+      const question = await program.account.question.fetch(questionPda);
+      expect(question.options).to.have.lengthOf(6);
+      expect(question.questionType).to.deep.equal({ multiSelect: { correctMask: 0b010101 } });
+    });
+
+    it("Submits a maximum-length question with the maximum number of options", async () => {
+      const questionPda = await submit({
+        questionText: "Q".repeat(500),
+        options: ["A", "B", "C", "D", "E", "F"].map((letter) => letter.repeat(100)),
+        questionType: { multiSelect: { correctMask: 0b111111 } },
+        category: "C".repeat(50),
+        difficulty: 3,
+      });
+
+      const question = await program.account.question.fetch(questionPda);
+      expect(question.questionText).to.have.lengthOf(500);
+      expect(question.options).to.have.lengthOf(6);
+      question.options.forEach((option: string) => expect(option).to.have.lengthOf(100));
+      expect(question.category).to.have.lengthOf(50);
+    });
+
+    const invalidQuestions = [
+      {
+        name: "true/false question with options",
+        options: ["True", "False"],
+        questionType: { trueFalse: { answer: false } },
+      },
+      {
+        name: "multiple choice with one option",
+        options: ["Only"],
+        questionType: { multipleChoice: { correctIndex: 0 } },
+      },
+      {
+        name: "multiple choice with seven options",
+        options: ["A", "B", "C", "D", "E", "F", "G"],
+        questionType: { multipleChoice: { correctIndex: 0 } },
+      },
+      {
+        name: "multi-select mask beyond the options",
+        options: ["A", "B", "C"],
+        questionType: { multiSelect: { correctMask: 0b1000 } },
+      },
+      {
+        name: "multi-select with an empty mask",
+        options: ["A", "B", "C"],
+        questionType: { multiSelect: { correctMask: 0 } },
+      },
+    ];
+
+    for (const invalid of invalidQuestions) {
+      it(`Rejects a ${invalid.name}`, async () => {
+        try {
+          await submit({
+            questionText: "Test question",
+            options: invalid.options,
+            questionType: invalid.questionType,
+            category: "Test",
+            difficulty: 1,
+          });
+
+          expect.fail("Should have failed");
+        } catch (error) {
+          expect(error.error.errorCode.code).to.equal("InvalidQuestionFormat");
+        }
+      });
+    }
+  });
+
+  describe("Vote Staking", () => {
+    let stakeMint: PublicKey;
+    let stakeVaultPda: PublicKey;
+    let user2TokenAccount: PublicKey;
+    let stakedQuestionPda: PublicKey;
+
+    before(async () => {
+      [stakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault")],
+        program.programId
+      );
+
+      stakeMint = await createMint(
+        provider.connection,
+        authority,
+        authority.publicKey,
+        null,
+        6
+      );
+      user2TokenAccount = await createAccount(
+        provider.connection,
+        user2,
+        stakeMint,
+        user2.publicKey
+      );
+      await mintTo(
+        provider.connection,
+        authority,
+        stakeMint,
+        user2TokenAccount,
+        authority,
+        1_000_000
+      );
+    });
+
+    it("Rejects a vote stake before the stake vault exists", async () => {
+      try {
+        await program.methods
+          .updateBankConfig({
+            ...emptyConfigUpdate(),
+            voteStake: new anchor.BN(100_000),
+          })
+          .accounts({
+            questionBank: questionBankPda,
+            bankConfig: bankConfigPda,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("InvalidConfig");
+      }
+    });
+
+    it("Initializes the stake vault and enables staking", async () => {
+      await program.methods
+        .initializeStakeVault()
+        .accounts({
+          questionBank: questionBankPda,
+          bankConfig: bankConfigPda,
+          stakeVault: stakeVaultPda,
+          stakeMint,
+          authority: authority.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+      await program.methods
+        .updateBankConfig({
+          ...emptyConfigUpdate(),
+          voteStake: new anchor.BN(100_000),
+        })
+        .accounts({
+          questionBank: questionBankPda,
+          bankConfig: bankConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      const bankConfig = await program.account.bankConfig.fetch(bankConfigPda);
+      expect(bankConfig.stakeMint.toString()).to.equal(stakeMint.toString());
+      expect(bankConfig.voteStake.toNumber()).to.equal(100_000);
+      expect(bankConfig.stakeSlashBps).to.equal(5000);
+    });
+
+    it("Locks the stake when voting", async () => {
+      const questionBank = await program.account.questionBank.fetch(questionBankPda);
+      [stakedQuestionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("question"), questionBank.totalQuestions.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      await program.methods
+        .submitQuestion({
+          questionText: "Which planet is known as the Red Planet?",
+          options: ["Venus", "Mars", "Jupiter", "Saturn"],
+          questionType: { multipleChoice: { correctIndex: 1 } },
+          category: "Science",
+          difficulty: 1,
+        })
+        .accounts({
+          question: stakedQuestionPda,
+          questionBank: questionBankPda,
+          bankConfig: bankConfigPda,
+          userReputation: user1ReputationPda,
+          submitter: user1.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const [voteRecordPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), stakedQuestionPda.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .voteOnQuestion({ approve: {} })
+        .accounts({
+          question: stakedQuestionPda,
+          voteRecord: voteRecordPda,
+          bankConfig: bankConfigPda,
+          userReputation: user2ReputationPda,
+          voterTokenAccount: user2TokenAccount,
+          stakeVault: stakeVaultPda,
+          stakeMint,
+          voter: user2.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+      const voteRecord = await program.account.voteRecord.fetch(voteRecordPda);
+      expect(voteRecord.stake.toNumber()).to.equal(100_000);
+      expect(voteRecord.stakeClaimed).to.equal(false);
+
+      const question = await program.account.question.fetch(stakedQuestionPda);
+      expect(question.stakeApprove.toNumber()).to.equal(100_000);
+      expect(question.stakeReject.toNumber()).to.equal(0);
+
+      const vault = await getAccount(provider.connection, stakeVaultPda);
+      expect(Number(vault.amount)).to.equal(100_000);
+    });
+
+    it("Fails to vote without stake accounts while staking is enabled", async () => {
+      const [voteRecordPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), stakedQuestionPda.toBuffer(), curator.publicKey.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .voteOnQuestion({ reject: {} })
+          .accounts({
+            question: stakedQuestionPda,
+            voteRecord: voteRecordPda,
+            bankConfig: bankConfigPda,
+            userReputation: curatorReputationPda,
+            voterTokenAccount: null,
+            stakeVault: null,
+            stakeMint: null,
+            voter: curator.publicKey,
+            tokenProgram: null,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([curator])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("MissingStakeAccounts");
+      }
+    });
+
+    it("Fails to claim a stake before finalization", async () => {
+      const [voteRecordPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), stakedQuestionPda.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .claimVoteStake()
+          .accounts({
+            question: stakedQuestionPda,
+            bankConfig: bankConfigPda,
+            voteRecord: voteRecordPda,
+            stakeVault: stakeVaultPda,
+            stakeMint,
+            voterTokenAccount: user2TokenAccount,
+            voter: user2.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([user2])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("QuestionNotFinalized");
+      }
+    });
+
+    after(async () => {
+      // Disable staking again so other suites vote without stake accounts
+      await program.methods
+        .updateBankConfig({
+          ...emptyConfigUpdate(),
+          voteStake: new anchor.BN(0),
+        })
+        .accounts({
+          questionBank: questionBankPda,
+          bankConfig: bankConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+    });
+  });
+
+  describe("Question Review", () => {
+    let reviewedQuestionPda: PublicKey;
+    let flagPda: PublicKey;
+
+    const reviewVotePda = (voter: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("review_vote"), flagPda.toBuffer(), voter.toBuffer()],
+        program.programId
+      )[0];
+
+    before(async () => {
+      // Question 0 was approved during finalization
+      [reviewedQuestionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("question"), Buffer.from([0, 0, 0, 0, 0, 0, 0, 0])],
+        program.programId
+      );
+      [flagPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("flag"), reviewedQuestionPda.toBuffer(), Buffer.from([0, 0, 0, 0])],
+        program.programId
+      );
+
+      // Only two curators exist in this suite
+      await program.methods
+        .updateBankConfig({
+          ...emptyConfigUpdate(),
+          minReviewVotes: 2,
+        })
+        .accounts({
+          questionBank: questionBankPda,
+          bankConfig: bankConfigPda,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+    });
+
+    it("Flags an approved question", async () => {
+      await program.methods
+        .flagQuestion("The answer is outdated", null)
+        .accounts({
+          question: reviewedQuestionPda,
+          flag: flagPda,
+          bankConfig: bankConfigPda,
+          flagger: user2.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+      const question = await program.account.question.fetch(reviewedQuestionPda);
+      expect(question.status).to.deep.equal({ underReview: {} });
+      expect(question.flagCount).to.equal(1);
+
+      const flag = await program.account.questionFlag.fetch(flagPda);
+      expect(flag.flagger.toString()).to.equal(user2.publicKey.toString());
+      expect(flag.bond.toNumber()).to.equal(50_000_000);
+      expect(flag.outcome).to.be.null;
+    });
+
+    it("Fails to vote for a fix the flag did not propose", async () => {
+      try {
+        await program.methods
+          .voteOnReview({ fix: {} })
+          .accounts({
+            question: reviewedQuestionPda,
+            flag: flagPda,
+            reviewVote: reviewVotePda(authority.publicKey),
+            questionBank: questionBankPda,
+            curator: authority.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([authority])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("NoProposedFix");
+      }
+    });
+
+    it("Fails to cast a review vote without curator privileges", async () => {
+      try {
+        await program.methods
+          .voteOnReview({ retire: {} })
+          .accounts({
+            question: reviewedQuestionPda,
+            flag: flagPda,
+            reviewVote: reviewVotePda(user1.publicKey),
+            questionBank: questionBankPda,
+            curator: user1.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("UnauthorizedCurator");
+      }
+    });
+
+    it("Retires the question once curators agree", async () => {
+      for (const reviewer of [authority, curator]) {
+        await program.methods
+          .voteOnReview({ retire: {} })
+          .accounts({
+            question: reviewedQuestionPda,
+            flag: flagPda,
+            reviewVote: reviewVotePda(reviewer.publicKey),
+            questionBank: questionBankPda,
+            curator: reviewer.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([reviewer])
+          .rpc();
+      }
+
+      const bankBefore = await program.account.questionBank.fetch(questionBankPda);
+      const reputationBefore = await program.account.userReputation.fetch(user1ReputationPda);
+      const flaggerBalanceBefore = await provider.connection.getBalance(user2.publicKey);
+
+      await program.methods
+        .resolveReview()
+        .accounts({
+          question: reviewedQuestionPda,
+          flag: flagPda,
+          questionBank: questionBankPda,
+          bankConfig: bankConfigPda,
+          submitterReputation: user1ReputationPda,
+          curationPool: curationPoolPda,
+          flagger: user2.publicKey,
+        })
+        .rpc();
+
+      const question = await program.account.question.fetch(reviewedQuestionPda);
+      expect(question.status).to.deep.equal({ retired: {} });
+
+      const flag = await program.account.questionFlag.fetch(flagPda);
+      expect(flag.outcome).to.deep.equal({ retire: {} });
+      expect(flag.bond.toNumber()).to.equal(0);
+
+      // Retiring removes the question and reverses the approval reward
+      const bankAfter = await program.account.questionBank.fetch(questionBankPda);
+      expect(bankAfter.activeQuestions.toNumber()).to.equal(bankBefore.activeQuestions.toNumber() - 1);
+
+      const reputationAfter = await program.account.userReputation.fetch(user1ReputationPda);
+      expect(reputationAfter.questionsApproved).to.equal(reputationBefore.questionsApproved - 1);
+      expect(reputationAfter.reputationScore.toNumber()).to.equal(
+        reputationBefore.reputationScore.toNumber() - 50
+      );
+
+      // The flagger's bond is refunded
+      const flaggerBalanceAfter = await provider.connection.getBalance(user2.publicKey);
+      expect(flaggerBalanceAfter - flaggerBalanceBefore).to.equal(50_000_000);
+    });
+  });
+});
+
+function emptyConfigUpdate() {
+  return {
+    minSubmitReputation: null,
+    initialReputation: null,
+    minVotes: null,
+    votingPeriod: null,
+    weightedQuorum: null,
+    voteWeighting: null,
+    voteTiers: null,
+    submissionReward: null,
+    voteReward: null,
+    approvalReward: null,
+    rejectionPenalty: null,
+    maxQuestionLen: null,
+    maxOptionLen: null,
+    maxCategoryLen: null,
+    minDifficulty: null,
+    maxDifficulty: null,
+    submissionBond: null,
+    maxPendingQuestions: null,
+    flagBond: null,
+    minReviewVotes: null,
+    voteStake: null,
+    stakeSlashBps: null,
+  };
+}
@@ -1,7 +1,29 @@
 import * as anchor from "@coral-xyz/anchor";
 import { expect } from "chai";
-import { PublicKey, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
-import { getAccount, TOKEN_PROGRAM_ID, createMint, createAccount, mintTo } from "@solana/spl-token";
+import {
+  PublicKey,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  SystemProgram,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getMintLen,
+  createAccount,
+  createAssociatedTokenAccount,
+  createInitializeMintInstruction,
+  createInitializePermanentDelegateInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  createMint,
+  mintTo,
+  ExtensionType,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
 import TestSetup, { 
   MockDataGenerator, 
   TimeHelper, 
@@ -1564,4 +1586,327 @@ describe("Reward Distributor - Comprehensive Test Suite", () => {
       expect(pool.totalRewards.toString()).to.equal("18446744073709551615");
     });
   });
-});
\ No newline at end of file
+
+  describe("Token-2022 Reward Pools", () => {
+    const createToken2022Pool = async (id: number, tokenMint: PublicKey, initialFunding: number) => {
+      const authority = testSetup.authority;
+      const rewardPoolPda = PDAHelper.getRewardPoolPDA(id, testSetup.rewardProgram.programId);
+      const rewardVaultPda = PDAHelper.getRewardVaultPDA(id, testSetup.rewardProgram.programId);
+      const rewardVaultToken = getAssociatedTokenAddressSync(tokenMint, rewardVaultPda, true, TOKEN_2022_PROGRAM_ID);
+      const authorityTokenAccount = await fundToken2022Account(
+        testSetup,
+        tokenMint,
+        authority.publicKey,
+        initialFunding
+      );
+
+      await testSetup.rewardProgram.methods
+        .createRewardPool(
+          {
+            id: new anchor.BN(id),
+            name: "Token-2022 Rewards",
+            totalRewards: new anchor.BN(initialFunding),
+            rewardType: { splToken: {} },
+            tokenMint,
+            distributionCriteria: { equalShare: {} },
+            startTime: new anchor.BN(TimeHelper.future(3600)),
+            endTime: new anchor.BN(TimeHelper.future(3600 + 7 * 24 * 3600)),
+          },
+          new anchor.BN(initialFunding)
+        )
+        .accounts({
+          rewardPool: rewardPoolPda,
+          rewardVault: rewardVaultPda,
+          authority: authority.publicKey,
+          authorityTokenAccount,
+          rewardVaultToken,
+          tokenMint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([authority.keypair])
+        .rpc();
+
+      return { rewardPoolPda, rewardVaultToken };
+    };
+
+    it("should credit only the initial funding the vault received", async () => {
+      const initialFunding = 1_000_000;
+      const tokenMint = await createToken2022Mint(testSetup, ExtensionType.TransferFeeConfig);
+
+      const { rewardPoolPda, rewardVaultToken } = await createToken2022Pool(26_001, tokenMint, initialFunding);
+
+      const received = initialFunding - (initialFunding * TRANSFER_FEE_BPS) / 10_000;
+      const vault = await getAccount(
+        testSetup.provider.connection,
+        rewardVaultToken,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(Number(vault.amount)).to.equal(received);
+
+      const pool = await testSetup.rewardProgram.account.rewardPool.fetch(rewardPoolPda);
+      expect(pool.totalRewards.toNumber()).to.equal(received);
+    });
+
+    it("should reject mints with unsupported extensions", async () => {
+      const tokenMint = await createToken2022Mint(testSetup, ExtensionType.PermanentDelegate);
+
+      await AssertionHelper.assertError(
+        async () => {
+          await createToken2022Pool(26_002, tokenMint, 1_000_000);
+        },
+        "UnsupportedMintExtension"
+      );
+    });
+  });
+
+  describe("Allocated Prize Pools", () => {
+    const createAllocatedPool = async (id: anchor.BN, startIn: number) => {
+      const authority = testSetup.authority;
+      const [rewardPoolPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pool"), id.toArrayLike(Buffer, "le", 8)],
+        testSetup.rewardProgram.programId
+      );
+      const [rewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), id.toArrayLike(Buffer, "le", 8)],
+        testSetup.rewardProgram.programId
+      );
+      const rewardVaultToken = getAssociatedTokenAddressSync(testSetup.tokenMint, rewardVaultPda, true);
+
+      await testSetup.rewardProgram.methods
+        .createRewardPool(
+          {
+            id,
+            name: "Allocated Prizes",
+            totalRewards: new anchor.BN(1_000_000),
+            rewardType: { splToken: {} },
+            tokenMint: testSetup.tokenMint,
+            distributionCriteria: { allocated: {} },
+            startTime: new anchor.BN(TimeHelper.future(startIn)),
+            endTime: new anchor.BN(TimeHelper.future(startIn + 7 * 24 * 3600)),
+          },
+          new anchor.BN(1_000_000)
+        )
+        .accounts({
+          rewardPool: rewardPoolPda,
+          rewardVault: rewardVaultPda,
+          authority: authority.publicKey,
+          authorityTokenAccount: authority.tokenAccount,
+          rewardVaultToken,
+          tokenMint: testSetup.tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([authority.keypair])
+        .rpc();
+
+      return { rewardPoolPda, rewardVaultPda, rewardVaultToken };
+    };
+
+    it("should pay registered claims in full", async () => {
+      const poolId = 33_001;
+      const user = testSetup.users[1];
+      const { rewardPoolPda, rewardVaultPda, rewardVaultToken } = await createAllocatedPool(
+        new anchor.BN(poolId),
+        5
+      );
+      const userClaimPda = PDAHelper.getUserClaimPDA(poolId, user.publicKey, testSetup.rewardProgram.programId);
+
+      await testSetup.rewardProgram.methods
+        .registerClaim(new anchor.BN(poolId), user.publicKey, new anchor.BN(600_000))
+        .accounts({
+          rewardPool: rewardPoolPda,
+          userClaim: userClaimPda,
+          authority: testSetup.authority.publicKey,
+          payer: testSetup.authority.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([testSetup.authority.keypair])
+        .rpc();
+
+      const userClaim = await testSetup.rewardProgram.account.userClaim.fetch(userClaimPda);
+      expect(userClaim.totalEligible.toNumber()).to.equal(600_000);
+
+      await TimeHelper.wait(6);
+      const balanceBefore = await getAccount(testSetup.provider.connection, user.tokenAccount);
+      await testSetup.rewardProgram.methods
+        .claimRewards(new anchor.BN(poolId))
+        .accounts({
+          rewardPool: rewardPoolPda,
+          userClaim: userClaimPda,
+          rewardVault: rewardVaultPda,
+          user: user.publicKey,
+          userTokenAccount: user.tokenAccount,
+          rewardVaultToken,
+          tokenMint: testSetup.tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user.keypair])
+        .rpc();
+
+      const balanceAfter = await getAccount(testSetup.provider.connection, user.tokenAccount);
+      expect(Number(balanceAfter.amount - balanceBefore.amount)).to.equal(600_000);
+      const pool = await testSetup.rewardProgram.account.rewardPool.fetch(rewardPoolPda);
+      expect(pool.distributedRewards.toNumber()).to.equal(600_000);
+    });
+
+    it("should fail to calculate performance rewards from an allocated pool", async () => {
+      const poolId = 33_002;
+      const user = testSetup.users[2];
+      const { rewardPoolPda } = await createAllocatedPool(new anchor.BN(poolId), 5);
+      await TimeHelper.wait(6);
+
+      await AssertionHelper.assertError(
+        async () => {
+          await testSetup.rewardProgram.methods
+            .calculateUserRewards(new anchor.BN(poolId), {
+              score: 90,
+              completionTime: new anchor.BN(120),
+              stakingDuration: new anchor.BN(0),
+              achievementsUnlocked: 3,
+              randomSeed: new anchor.BN(42),
+              honeycombProfile: null,
+            })
+            .accounts({
+              rewardPool: rewardPoolPda,
+              userClaim: PDAHelper.getUserClaimPDA(poolId, user.publicKey, testSetup.rewardProgram.programId),
+              user: user.publicKey,
+              honeycombProfile: null,
+              systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .signers([user.keypair])
+            .rpc();
+        },
+        "AllocatedPool"
+      );
+    });
+
+    it("should reserve tournament pool IDs for tournament_manager", async () => {
+      await AssertionHelper.assertError(
+        async () => {
+          await createAllocatedPool(TOURNAMENT_POOL_ID_FLAG.or(new anchor.BN(33_003)), 3600);
+        },
+        "ReservedPoolId"
+      );
+    });
+
+    it("should fail to create a tournament pool without the tournament's signature", async () => {
+      const impostor = testSetup.users[3];
+      const poolId = TOURNAMENT_POOL_ID_FLAG.or(new anchor.BN(0));
+      const [rewardPoolPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pool"), poolId.toArrayLike(Buffer, "le", 8)],
+        testSetup.rewardProgram.programId
+      );
+      const [rewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), poolId.toArrayLike(Buffer, "le", 8)],
+        testSetup.rewardProgram.programId
+      );
+
+      await AssertionHelper.assertError(
+        async () => {
+          await testSetup.rewardProgram.methods
+            .createTournamentPool(
+              {
+                id: poolId,
+                name: "Tournament 0 prizes",
+                totalRewards: new anchor.BN(1_000),
+                rewardType: { splToken: {} },
+                tokenMint: testSetup.tokenMint,
+                distributionCriteria: { allocated: {} },
+                startTime: new anchor.BN(TimeHelper.future(60)),
+                endTime: new anchor.BN(TimeHelper.future(3600)),
+              },
+              new anchor.BN(1_000)
+            )
+            .accounts({
+              rewardPool: rewardPoolPda,
+              rewardVault: rewardVaultPda,
+              authority: impostor.publicKey,
+              payer: impostor.publicKey,
+              authorityTokenAccount: impostor.tokenAccount,
+              rewardVaultToken: getAssociatedTokenAddressSync(testSetup.tokenMint, rewardVaultPda, true),
+              tokenMint: testSetup.tokenMint,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+              systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .signers([impostor.keypair])
+            .rpc();
+        },
+        "UnauthorizedAuthority"
+      );
+    });
+  });
+});
+
+// ============================================================================
+// Fixtures
+// ============================================================================
+
+/** Transfer fee charged by mints from `createToken2022Mint` */
+const TRANSFER_FEE_BPS = 100;
+
+/** Pool ID bit reserved for tournament prize pools */
+const TOURNAMENT_POOL_ID_FLAG = new anchor.BN(1).shln(63);
+
+/**
+ * Create a 6-decimal Token-2022 mint with a single extension. Transfer fee
+ * mints charge `TRANSFER_FEE_BPS`; permanent delegate mints are unsupported.
+ */
+async function createToken2022Mint(setup: TestSetup, extension: ExtensionType): Promise<PublicKey> {
+  const connection = setup.provider.connection;
+  const payer = setup.authority.keypair;
+  const mint = Keypair.generate();
+  const space = getMintLen([extension]);
+
+  const transaction = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: payer.publicKey,
+      newAccountPubkey: mint.publicKey,
+      space,
+      lamports: await connection.getMinimumBalanceForRentExemption(space),
+      programId: TOKEN_2022_PROGRAM_ID,
+    }),
+    extension === ExtensionType.TransferFeeConfig
+      ? createInitializeTransferFeeConfigInstruction(
+          mint.publicKey,
+          payer.publicKey,
+          payer.publicKey,
+          TRANSFER_FEE_BPS,
+          BigInt(1_000_000_000),
+          TOKEN_2022_PROGRAM_ID
+        )
+      : createInitializePermanentDelegateInstruction(mint.publicKey, payer.publicKey, TOKEN_2022_PROGRAM_ID),
+    createInitializeMintInstruction(mint.publicKey, 6, payer.publicKey, null, TOKEN_2022_PROGRAM_ID)
+  );
+  await sendAndConfirmTransaction(connection, transaction, [payer, mint]);
+
+  return mint.publicKey;
+}
+
+/** Create `owner`'s associated account for a Token-2022 mint and mint `amount` into it */
+async function fundToken2022Account(
+  setup: TestSetup,
+  mint: PublicKey,
+  owner: PublicKey,
+  amount: number
+): Promise<PublicKey> {
+  const connection = setup.provider.connection;
+  const payer = setup.authority.keypair;
+
+  const tokenAccount = await createAssociatedTokenAccount(
+    connection,
+    payer,
+    mint,
+    owner,
+    undefined,
+    TOKEN_2022_PROGRAM_ID
+  );
+  await mintTo(connection, payer, mint, tokenAccount, payer, amount, [], undefined, TOKEN_2022_PROGRAM_ID);
+
+  return tokenAccount;
+}
@@ -1,7 +1,27 @@
 import * as anchor from "@coral-xyz/anchor";
 import { expect } from "chai";
-import { PublicKey, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
-import { getAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  PublicKey,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  SystemProgram,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getMintLen,
+  mintTo,
+  createAssociatedTokenAccount,
+  createInitializeMintInstruction,
+  createInitializePermanentDelegateInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  ExtensionType,
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
 import TestSetup, { 
   MockDataGenerator, 
   TimeHelper, 
@@ -19,6 +39,8 @@ describe("Tournament Manager - Comprehensive Test Suite", () => {
   // Tournament data
   let tournamentId: number = 1;
   let nextTournamentId: number = 2;
+  // Approved "General" questions for tournaments that get started
+  let generalQuestions: PublicKey[];
 
   before(async () => {
     console.log("🚀 Starting Tournament Manager Test Suite");
@@ -27,6 +49,8 @@ describe("Tournament Manager - Comprehensive Test Suite", () => {
     gasTracker = new GasTracker();
     
     await testSetup.initialize();
+    await registerOrganizer(testSetup, testSetup.authority);
+    generalQuestions = await seedApprovedQuestions(testSetup, 5, "General", 1);
     
     console.log("✅ Test setup complete");
   });
@@ -477,18 +501,18 @@ describe("Tournament Manager - Comprehensive Test Suite", () => {
       );
     });
 
-    it("should prevent starting tournament before start time", async () => {
-      const organizer = testSetup.authority;
+    it("should prevent cranking tournament start before start time", async () => {
+      const cranker = testSetup.users[5];
 
       await AssertionHelper.assertError(
         async () => {
           await testSetup.tournamentProgram.methods
-            .startTournament()
+            .crankStartTournament()
             .accounts({
               tournament: tournamentPda,
-              organizer: organizer.publicKey,
+              cranker: cranker.publicKey,
             })
-            .signers([organizer.keypair])
+            .signers([cranker.keypair])
             .rpc();
         },
         "TournamentNotReady"
@@ -837,7 +861,7 @@ describe("Tournament Manager - Comprehensive Test Suite", () => {
       expect(tournament.endedAt).to.not.be.null;
     });
 
-    it("should prevent ending tournament before time", async () => {
+    it("should prevent cranking tournament end before time", async () => {
       // Create a new tournament that just started
       const futureTournamentId = 20;
       const organizer = testSetup.authority;
@@ -885,16 +909,17 @@ describe("Tournament Manager - Comprehensive Test Suite", () => {
         .signers([organizer.keypair])
         .rpc();
 
-      // Try to end immediately (should fail)
+      // Try to crank the end immediately (should fail)
+      const cranker = testSetup.users[5];
       await AssertionHelper.assertError(
         async () => {
           await testSetup.tournamentProgram.methods
-            .endTournament()
+            .crankEndTournament()
             .accounts({
               tournament: futureTournamentPda,
-              organizer: organizer.publicKey,
+              cranker: cranker.publicKey,
             })
-            .signers([organizer.keypair])
+            .signers([cranker.keypair])
             .rpc();
         },
         "TournamentNotEnded"
@@ -1425,4 +1450,1907 @@ describe("Tournament Manager - Comprehensive Test Suite", () => {
       );
     });
   });
-});
\ No newline at end of file
+
+  describe("Token-2022 Mints", () => {
+    it("should credit the prize pool with entry fees net of the transfer fee", async () => {
+      const entryFee = 1_000_000;
+      const player = testSetup.users[0];
+      const tokenMint = await createToken2022Mint(testSetup, ExtensionType.TransferFeeConfig);
+      const playerTokenAccount = await fundToken2022Account(testSetup, tokenMint, player.publicKey, 10 * entryFee);
+
+      const t = await createTestTournament(testSetup, {
+        entryFee,
+        tokenMint,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      });
+      await registerPlayer(testSetup, t, player, { participantTokenAccount: playerTokenAccount });
+
+      const received = entryFee - (entryFee * TRANSFER_FEE_BPS) / 10_000;
+      const tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.tokenMint.toString()).to.equal(tokenMint.toString());
+      expect(tournament.prizePool.toNumber()).to.equal(received);
+
+      const vault = await getAccount(
+        testSetup.provider.connection,
+        t.tournamentVault,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(Number(vault.amount)).to.equal(received);
+    });
+
+    it("should reject mints with unsupported extensions", async () => {
+      const tokenMint = await createToken2022Mint(testSetup, ExtensionType.PermanentDelegate);
+
+      await AssertionHelper.assertError(
+        async () => {
+          await createTestTournament(testSetup, {
+            tokenMint,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          });
+        },
+        "UnsupportedMintExtension"
+      );
+    });
+  });
+
+  describe("Permissionless Crank", () => {
+    const crankBounty = 0.02 * LAMPORTS_PER_SOL;
+
+    it("should pay the bounty to whoever starts and ends the tournament", async () => {
+      const t = await createTestTournament(testSetup, { startIn: 5, duration: 5, crankBounty, questions: generalQuestions });
+      await registerPlayer(testSetup, t, testSetup.users[1]);
+      await registerPlayer(testSetup, t, testSetup.users[2]);
+
+      const cranker = testSetup.users[3];
+      const connection = testSetup.provider.connection;
+
+      await TimeHelper.wait(6);
+      const balanceBeforeStart = await connection.getBalance(cranker.publicKey);
+      await testSetup.tournamentProgram.methods
+        .crankStartTournament()
+        .accounts({ tournament: t.tournament, cranker: cranker.publicKey })
+        .signers([cranker.keypair])
+        .rpc();
+
+      let tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.status).to.deep.equal({ active: {} });
+      expect(tournament.crankBounty.toNumber()).to.equal(crankBounty / 2);
+      expect(await connection.getBalance(cranker.publicKey)).to.equal(balanceBeforeStart + crankBounty / 2);
+
+      await TimeHelper.wait(6);
+      const balanceBeforeEnd = await connection.getBalance(cranker.publicKey);
+      await testSetup.tournamentProgram.methods
+        .crankEndTournament()
+        .accounts({ tournament: t.tournament, cranker: cranker.publicKey })
+        .signers([cranker.keypair])
+        .rpc();
+
+      tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.status).to.deep.equal({ ended: {} });
+      expect(tournament.crankBounty.toNumber()).to.equal(0);
+      expect(await connection.getBalance(cranker.publicKey)).to.equal(balanceBeforeEnd + crankBounty / 2);
+    });
+
+    it("should let the organizer start and end a tournament early", async () => {
+      const t = await createTestTournament(testSetup, { startIn: 3600, duration: 3600, questions: generalQuestions });
+      await registerPlayer(testSetup, t, testSetup.users[1]);
+      await registerPlayer(testSetup, t, testSetup.users[2]);
+
+      await startTournament(testSetup, t);
+      let tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.status).to.deep.equal({ active: {} });
+      expect(tournament.actualStartTime.toNumber()).to.be.below(tournament.startTime.toNumber());
+
+      await endTournament(testSetup, t);
+      tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.status).to.deep.equal({ ended: {} });
+    });
+  });
+
+  describe("Moderation", () => {
+    const entryFee = 1_000_000;
+    let moderator: TestUser;
+    let tournamentManagerPda: PublicKey;
+    let t: TestTournament;
+
+    const disqualify = (player: TestUser, signer: TestUser, forfeitEntryFee: boolean) =>
+      testSetup.tournamentProgram.methods
+        .disqualifyParticipant(1, forfeitEntryFee)
+        .accounts({
+          tournamentManager: tournamentManagerPda,
+          tournament: t.tournament,
+          registration: PDAHelper.getRegistrationPDA(t.tournament, player.publicKey, testSetup.tournamentProgram.programId),
+          playerProfile: playerProfilePda(testSetup, player),
+          leaderboard: t.leaderboard,
+          tournamentVault: t.tournamentVault,
+          participantTokenAccount: player.tokenAccount,
+          tokenMint: t.tokenMint,
+          moderator: signer.publicKey,
+          tokenProgram: t.tokenProgram,
+        })
+        .signers([signer.keypair])
+        .rpc();
+
+    before(async () => {
+      moderator = await testSetup.createTestUser("Moderator", 2);
+      ({ tournamentManagerPda } = PDAHelper.getTournamentManagerPDAs(testSetup.tournamentProgram.programId));
+
+      await testSetup.tournamentProgram.methods
+        .addModerator(moderator.publicKey)
+        .accounts({
+          tournamentManager: tournamentManagerPda,
+          authority: testSetup.authority.publicKey,
+        })
+        .signers([testSetup.authority.keypair])
+        .rpc();
+
+      t = await createTestTournament(testSetup, { entryFee });
+      await registerPlayer(testSetup, t, testSetup.users[4]);
+      await registerPlayer(testSetup, t, testSetup.users[5]);
+    });
+
+    it("should let a moderator disqualify a participant and refund the entry fee", async () => {
+      const player = testSetup.users[4];
+      const balanceBefore = await getAccount(testSetup.provider.connection, player.tokenAccount);
+
+      await disqualify(player, moderator, false);
+
+      const registration = await testSetup.tournamentProgram.account.registration.fetch(
+        PDAHelper.getRegistrationPDA(t.tournament, player.publicKey, testSetup.tournamentProgram.programId)
+      );
+      expect(registration.disqualified).to.be.true;
+      expect(registration.disqualificationReason).to.equal(1);
+      expect(registration.entryFeePaid.toNumber()).to.equal(0);
+
+      const tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.currentParticipants).to.equal(1);
+      expect(tournament.prizePool.toNumber()).to.equal(entryFee);
+
+      const balanceAfter = await getAccount(testSetup.provider.connection, player.tokenAccount);
+      expect(Number(balanceAfter.amount - balanceBefore.amount)).to.equal(entryFee);
+    });
+
+    it("should fail to disqualify without moderator privileges", async () => {
+      await AssertionHelper.assertError(
+        async () => {
+          await disqualify(testSetup.users[5], testSetup.users[6], true);
+        },
+        "UnauthorizedModerator"
+      );
+    });
+
+    it("should keep banned players from registering until unbanned", async () => {
+      const player = testSetup.users[7];
+      const banList = {
+        tournamentManager: tournamentManagerPda,
+        moderator: moderator.publicKey,
+      };
+
+      await testSetup.tournamentProgram.methods
+        .banPlayer(player.publicKey)
+        .accounts(banList)
+        .signers([moderator.keypair])
+        .rpc();
+
+      await AssertionHelper.assertError(
+        async () => {
+          await registerPlayer(testSetup, t, player);
+        },
+        "PlayerBanned"
+      );
+
+      await testSetup.tournamentProgram.methods
+        .unbanPlayer(player.publicKey)
+        .accounts(banList)
+        .signers([moderator.keypair])
+        .rpc();
+      await registerPlayer(testSetup, t, player);
+
+      const tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.currentParticipants).to.equal(2);
+    });
+  });
+
+  describe("Question Disputes", () => {
+    let t: TestTournament;
+    let tournamentManagerPda: PublicKey;
+    let questionBankPda: PublicKey;
+    const disputer = () => testSetup.users[1];
+
+    const resolve = (questionIndex: number, uphold: boolean, curator: TestUser) =>
+      testSetup.tournamentProgram.methods
+        .resolveDispute(uphold)
+        .accounts({
+          tournamentManager: tournamentManagerPda,
+          tournament: t.tournament,
+          dispute: disputePda(testSetup, t, questionIndex, disputer()),
+          leaderboard: t.leaderboard,
+          questionBank: questionBankPda,
+          disputer: disputer().publicKey,
+          treasury: testSetup.authority.publicKey,
+          curator: curator.publicKey,
+        })
+        .signers([curator.keypair])
+        .rpc();
+
+    before(async () => {
+      ({ tournamentManagerPda } = PDAHelper.getTournamentManagerPDAs(testSetup.tournamentProgram.programId));
+      ({ questionBankPda } = PDAHelper.getQuestionBankPDAs(testSetup.questionBankProgram.programId));
+
+      t = await createTestTournament(testSetup, { startIn: 3600, questions: generalQuestions });
+      await registerPlayer(testSetup, t, testSetup.users[1]);
+      await registerPlayer(testSetup, t, testSetup.users[2]);
+      await startTournament(testSetup, t);
+      await submitAnswers(testSetup, t, testSetup.users[1], [0, 1, 2, 3, 0]);
+      await submitAnswers(testSetup, t, testSetup.users[2], [1, 1, 1, 1, 1]);
+      await endTournament(testSetup, t);
+    });
+
+    it("should escrow the bond when a participant opens a dispute", async () => {
+      const connection = testSetup.provider.connection;
+      const dispute = disputePda(testSetup, t, 0, disputer());
+
+      await openDispute(testSetup, t, 0, disputer());
+
+      const account = await testSetup.tournamentProgram.account.dispute.fetch(dispute);
+      expect(account.questionIndex).to.equal(0);
+      expect(account.bond.toNumber()).to.equal(DISPUTE_BOND);
+      expect(account.status).to.deep.equal({ open: {} });
+      expect(await connection.getBalance(dispute)).to.be.at.least(DISPUTE_BOND);
+
+      const tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.openDisputes).to.equal(1);
+    });
+
+    it("should fail to resolve a dispute without curator privileges", async () => {
+      await AssertionHelper.assertError(
+        async () => {
+          await resolve(0, true, testSetup.users[3]);
+        },
+        "UnauthorizedCurator"
+      );
+    });
+
+    it("should void the question, refund the bond and re-score on uphold", async () => {
+      const connection = testSetup.provider.connection;
+      const dispute = disputePda(testSetup, t, 0, disputer());
+      const balanceBefore = await connection.getBalance(disputer().publicKey);
+      const disputeBalance = await connection.getBalance(dispute);
+
+      await resolve(0, true, testSetup.curators[0]);
+      expect(await connection.getAccountInfo(dispute)).to.be.null;
+
+      let tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.voidedQuestions.toNumber()).to.equal(1);
+      expect(tournament.openDisputes).to.equal(0);
+      expect(tournament.pendingRescores).to.equal(2);
+      expect(await connection.getBalance(disputer().publicKey)).to.equal(balanceBefore + disputeBalance);
+
+      const registration = PDAHelper.getRegistrationPDA(
+        t.tournament,
+        disputer().publicKey,
+        testSetup.tournamentProgram.programId
+      );
+      const scoreBefore = (await testSetup.tournamentProgram.account.registration.fetch(registration)).score;
+
+      await testSetup.tournamentProgram.methods
+        .rescoreRegistration()
+        .accounts({
+          tournament: t.tournament,
+          registration,
+          playerProfile: playerProfilePda(testSetup, disputer()),
+          leaderboard: t.leaderboard,
+        })
+        .rpc();
+
+      const rescored = await testSetup.tournamentProgram.account.registration.fetch(registration);
+      expect(rescored.score).to.be.below(scoreBefore);
+      tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.pendingRescores).to.equal(1);
+    });
+
+    it("should fail to dispute a voided or out-of-range question", async () => {
+      await AssertionHelper.assertError(
+        async () => {
+          await openDispute(testSetup, t, 0, testSetup.users[2]);
+        },
+        "QuestionAlreadyVoided"
+      );
+      await AssertionHelper.assertError(
+        async () => {
+          await openDispute(testSetup, t, 5, testSetup.users[2]);
+        },
+        "InvalidQuestionIndex"
+      );
+    });
+  });
+
+  describe("Tournament Updates", () => {
+    let t: TestTournament;
+
+    const emptyUpdate = {
+      description: null,
+      startTime: null,
+      maxParticipants: null,
+      entryFee: null,
+      category: null,
+      difficulty: null,
+    };
+
+    before(async () => {
+      t = await createTestTournament(testSetup, { startIn: 3600, maxParticipants: 10 });
+    });
+
+    it("should let the organizer edit a tournament before it starts", async () => {
+      const tournamentBefore = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      const startTime = tournamentBefore.startTime.addn(600);
+
+      await updateTournament(testSetup, t, {
+        ...emptyUpdate,
+        description: "Rescheduled",
+        startTime,
+        maxParticipants: 20,
+        entryFee: new anchor.BN(2_000_000),
+        category: "Science",
+      });
+
+      const tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.description).to.equal("Rescheduled");
+      expect(tournament.startTime.toNumber()).to.equal(startTime.toNumber());
+      expect(tournament.maxParticipants).to.equal(20);
+      expect(tournament.entryFee.toNumber()).to.equal(2_000_000);
+      expect(tournament.category).to.equal("Science");
+      expect(tournament.updateCount).to.equal(1);
+    });
+
+    it("should fail to move the start time earlier or shrink the field", async () => {
+      const tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+
+      await AssertionHelper.assertError(
+        async () => {
+          await updateTournament(testSetup, t, { ...emptyUpdate, startTime: tournament.startTime.subn(1) });
+        },
+        "InvalidStartTime"
+      );
+      await AssertionHelper.assertError(
+        async () => {
+          await updateTournament(testSetup, t, { ...emptyUpdate, maxParticipants: 5 });
+        },
+        "InvalidMaxParticipants"
+      );
+    });
+
+    it("should fail with an overlong category", async () => {
+      await AssertionHelper.assertError(
+        async () => {
+          await updateTournament(testSetup, t, { ...emptyUpdate, category: "x".repeat(51) });
+        },
+        "CategoryTooLong"
+      );
+      await AssertionHelper.assertError(
+        async () => {
+          await createTestTournament(testSetup, { category: "x".repeat(51) });
+        },
+        "CategoryTooLong"
+      );
+    });
+
+    it("should lock the entry fee once players have registered", async () => {
+      await registerPlayer(testSetup, t, testSetup.users[1]);
+
+      await AssertionHelper.assertError(
+        async () => {
+          await updateTournament(testSetup, t, { ...emptyUpdate, entryFee: new anchor.BN(1) });
+        },
+        "EntryFeeLocked"
+      );
+    });
+
+    it("should fail when someone other than the organizer edits", async () => {
+      await AssertionHelper.assertError(
+        async () => {
+          await updateTournament(testSetup, { ...t, organizer: testSetup.users[2] }, {
+            ...emptyUpdate,
+            description: "Hijacked",
+          });
+        },
+        "ConstraintHasOne"
+      );
+    });
+  });
+
+  describe("Player Profiles", () => {
+    const player = () => testSetup.users[8];
+    let t: TestTournament;
+
+    before(async () => {
+      t = await createTestTournament(testSetup, { startIn: 3600, questions: generalQuestions });
+      await registerPlayer(testSetup, t, player());
+      await registerPlayer(testSetup, t, testSetup.users[9]);
+    });
+
+    it("should create the profile on first registration", async () => {
+      const profile = await testSetup.tournamentProgram.account.playerProfile.fetch(
+        playerProfilePda(testSetup, player())
+      );
+      expect(profile.player.toString()).to.equal(player().publicKey.toString());
+      expect(profile.tournamentsEntered).to.equal(1);
+      expect(profile.tournamentsCompleted).to.equal(0);
+      expect(profile.bestRank).to.equal(0);
+    });
+
+    it("should carry statistics across tournaments", async () => {
+      const other = await createTestTournament(testSetup, { startIn: 3600 });
+      await registerPlayer(testSetup, other, player());
+
+      await startTournament(testSetup, t);
+      await submitAnswers(testSetup, t, player(), [0, 1, 2, 3, 0]);
+
+      const profile = await testSetup.tournamentProgram.account.playerProfile.fetch(
+        playerProfilePda(testSetup, player())
+      );
+      const registration = await testSetup.tournamentProgram.account.registration.fetch(
+        PDAHelper.getRegistrationPDA(t.tournament, player().publicKey, testSetup.tournamentProgram.programId)
+      );
+      expect(profile.tournamentsEntered).to.equal(2);
+      expect(profile.tournamentsCompleted).to.equal(1);
+      expect(profile.totalScore.toNumber()).to.equal(registration.score);
+    });
+  });
+
+  describe("Rating Brackets", () => {
+    const INITIAL_RATING = 1500;
+
+    it("should record the player's rating at registration", async () => {
+      const t = await createTestTournament(testSetup, { minRating: 1000, maxRating: 2000 });
+      await registerPlayer(testSetup, t, testSetup.users[1]);
+
+      const tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.minRating).to.equal(1000);
+      expect(tournament.maxRating).to.equal(2000);
+
+      const registration = await testSetup.tournamentProgram.account.registration.fetch(
+        PDAHelper.getRegistrationPDA(t.tournament, testSetup.users[1].publicKey, testSetup.tournamentProgram.programId)
+      );
+      expect(registration.ratingAtRegistration).to.equal(INITIAL_RATING);
+      expect(registration.ratingApplied).to.be.false;
+    });
+
+    it("should keep players outside the bracket from registering", async () => {
+      const experts = await createTestTournament(testSetup, { minRating: INITIAL_RATING + 1 });
+      const beginners = await createTestTournament(testSetup, { maxRating: INITIAL_RATING - 1 });
+
+      await AssertionHelper.assertError(
+        async () => {
+          await registerPlayer(testSetup, experts, testSetup.users[2]);
+        },
+        "RatingOutOfBracket"
+      );
+      await AssertionHelper.assertError(
+        async () => {
+          await registerPlayer(testSetup, beginners, testSetup.users[2]);
+        },
+        "RatingOutOfBracket"
+      );
+    });
+
+    it("should fail to create a tournament with an inverted bracket", async () => {
+      await AssertionHelper.assertError(
+        async () => {
+          await createTestTournament(testSetup, { minRating: 2000, maxRating: 1000 });
+        },
+        "InvalidRatingBracket"
+      );
+    });
+  });
+
+  describe("Prize Settlement", () => {
+    let t: TestTournament;
+
+    before(async () => {
+      t = await createTestTournament(testSetup, { startIn: 3600, entryFee: 1_000_000, questions: generalQuestions });
+      await registerPlayer(testSetup, t, testSetup.users[1]);
+      await registerPlayer(testSetup, t, testSetup.users[2]);
+      await startTournament(testSetup, t);
+      await submitAnswers(testSetup, t, testSetup.users[1], [0, 1, 2, 3, 0]);
+      await endTournament(testSetup, t);
+    });
+
+    it("should derive the prize pool ID from the tournament ID", () => {
+      const { poolId } = prizePoolPdas(testSetup, t);
+      expect(poolId.testn(63)).to.be.true;
+      expect(poolId.maskn(63).toNumber()).to.equal(t.id);
+    });
+
+    it("should keep prizes locked while the dispute window is open", async () => {
+      await AssertionHelper.assertError(
+        async () => {
+          await distributePrizes(testSetup, t, [testSetup.users[1]], [1_000_000]);
+        },
+        "DisputeWindowOpen"
+      );
+
+      const tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.status).to.deep.equal({ ended: {} });
+    });
+  });
+
+  describe("Session Keys", () => {
+    const player = () => testSetup.users[3];
+    let t: TestTournament;
+    let registration: PublicKey;
+    let session: Keypair;
+
+    before(async () => {
+      t = await createTestTournament(testSetup, { startIn: 3600, questions: generalQuestions });
+      await registerPlayer(testSetup, t, player());
+      await registerPlayer(testSetup, t, testSetup.users[4]);
+      registration = PDAHelper.getRegistrationPDA(t.tournament, player().publicKey, testSetup.tournamentProgram.programId);
+      session = Keypair.generate();
+    });
+
+    it("should reject sessions longer than a day", async () => {
+      await AssertionHelper.assertError(
+        async () => {
+          await createGameSession(testSetup, t, player(), session.publicKey, 24 * 3600 + 1);
+        },
+        "InvalidSessionDuration"
+      );
+    });
+
+    it("should let a session key submit answers for the participant", async () => {
+      await createGameSession(testSetup, t, player(), session.publicKey, 3600);
+
+      const gameSession = await testSetup.tournamentProgram.account.gameSession.fetch(
+        gameSessionPda(testSetup, registration)
+      );
+      expect(gameSession.sessionKey.toString()).to.equal(session.publicKey.toString());
+      expect(gameSession.expiresAt.sub(gameSession.createdAt).toNumber()).to.equal(3600);
+
+      await startTournament(testSetup, t);
+      await submitAnswers(testSetup, t, player(), [0, 1, 2, 3, 0], { session });
+
+      const account = await testSetup.tournamentProgram.account.registration.fetch(registration);
+      expect(account.completed).to.be.true;
+    });
+
+    it("should fail when a session key plays for another registration", async () => {
+      await createGameSession(testSetup, t, testSetup.users[4], Keypair.generate().publicKey, 3600);
+
+      await AssertionHelper.assertError(
+        async () => {
+          await submitAnswers(testSetup, t, testSetup.users[4], [0, 1, 2, 3, 0], { session });
+        },
+        "UnauthorizedSessionKey"
+      );
+    });
+
+    it("should return the session rent on revoke", async () => {
+      const gameSession = gameSessionPda(testSetup, registration);
+
+      await testSetup.tournamentProgram.methods
+        .revokeGameSession()
+        .accounts({ gameSession, participant: player().publicKey })
+        .signers([player().keypair])
+        .rpc();
+
+      expect(await testSetup.provider.connection.getAccountInfo(gameSession)).to.be.null;
+    });
+  });
+
+  describe("Saved Answers", () => {
+    let t: TestTournament;
+    const registrationOf = (player: TestUser) =>
+      PDAHelper.getRegistrationPDA(t.tournament, player.publicKey, testSetup.tournamentProgram.programId);
+
+    before(async () => {
+      t = await createTestTournament(testSetup, { startIn: 3600, questions: generalQuestions });
+      await registerPlayer(testSetup, t, testSetup.users[1]);
+      await registerPlayer(testSetup, t, testSetup.users[2]);
+      await registerPlayer(testSetup, t, testSetup.users[3]);
+      await startTournament(testSetup, t);
+    });
+
+    it("should save answers in chunks and finalize them", async () => {
+      const player = testSetup.users[1];
+
+      await saveAnswers(testSetup, t, player, 0, [1, 1]);
+      await saveAnswers(testSetup, t, player, 1, [2, 3]);
+
+      let registration = await testSetup.tournamentProgram.account.registration.fetch(registrationOf(player));
+      expect(Array.from(registration.answers)).to.deep.equal([1, 2, 3, 0, 0]);
+      expect(registration.savedQuestions.toNumber()).to.equal(0b111);
+      expect(registration.completed).to.be.false;
+
+      await finalizeAnswers(testSetup, t, player);
+
+      registration = await testSetup.tournamentProgram.account.registration.fetch(registrationOf(player));
+      expect(registration.completed).to.be.true;
+      const tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.pendingFinalizations).to.equal(0);
+    });
+
+    it("should fail to save past the last question or finalize nothing", async () => {
+      const player = testSetup.users[2];
+
+      await AssertionHelper.assertError(
+        async () => {
+          await saveAnswers(testSetup, t, player, 4, [0, 0]);
+        },
+        "InvalidAnswerCount"
+      );
+      await AssertionHelper.assertError(
+        async () => {
+          await finalizeAnswers(testSetup, t, player);
+        },
+        "NoAnswersSaved"
+      );
+    });
+
+    it("should let anyone finalize saved answers once the tournament ended", async () => {
+      const player = testSetup.users[3];
+      await saveAnswers(testSetup, t, player, 0, [0, 1, 2]);
+      await endTournament(testSetup, t);
+
+      await testSetup.tournamentProgram.methods
+        .crankFinalizeAnswers()
+        .accounts({
+          tournament: t.tournament,
+          registration: registrationOf(player),
+          playerProfile: playerProfilePda(testSetup, player),
+          leaderboard: t.leaderboard,
+        })
+        .remainingAccounts(questionAccounts(t.questions))
+        .rpc();
+
+      const registration = await testSetup.tournamentProgram.account.registration.fetch(registrationOf(player));
+      expect(registration.completed).to.be.true;
+      expect(registration.submissionTime.toNumber()).to.equal(registration.lastSavedAt.toNumber());
+    });
+  });
+
+  describe("Account Cleanup", () => {
+    let t: TestTournament;
+    const player = () => testSetup.users[1];
+
+    before(async () => {
+      t = await createTestTournament(testSetup, { startIn: 3600 });
+      await registerPlayer(testSetup, t, player());
+    });
+
+    it("should keep registrations open until the tournament is settled or cancelled", async () => {
+      await AssertionHelper.assertError(
+        async () => {
+          await closeRegistration(testSetup, t, player(), { tournament: t.tournament });
+        },
+        "InvalidStatus"
+      );
+    });
+
+    it("should fail to close a registration without its tournament or archive", async () => {
+      await AssertionHelper.assertError(
+        async () => {
+          await closeRegistration(testSetup, t, player(), {});
+        },
+        "InvalidTournamentAccounts"
+      );
+    });
+
+    it("should fail to close a tournament that has not been settled", async () => {
+      await AssertionHelper.assertError(
+        async () => {
+          await closeTournament(testSetup, t);
+        },
+        "InvalidStatus"
+      );
+    });
+  });
+
+  describe("Practice Tournaments", () => {
+    const category = "Practice";
+    const difficulty = 2;
+    let questions: PublicKey[];
+    let offTopic: PublicKey[];
+    let practice: TestPracticeTournament;
+
+    const addQuestions = (target: TestPracticeTournament, questionAccounts: PublicKey[]) =>
+      testSetup.tournamentProgram.methods
+        .addPracticeQuestions()
+        .accounts({ practiceTournament: target.practiceTournament, organizer: target.organizer.publicKey })
+        .remainingAccounts(questionAccounts.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })))
+        .signers([target.organizer.keypair])
+        .rpc();
+
+    before(async () => {
+      questions = await seedApprovedQuestions(testSetup, 6, category, difficulty);
+      offTopic = await seedApprovedQuestions(testSetup, 1, "Off Topic", difficulty);
+
+      practice = await createPracticeTournament(testSetup, { category, difficulty });
+      await addQuestions(practice, questions);
+    });
+
+    it("should fill the question pool with matching approved questions", async () => {
+      const account = await testSetup.tournamentProgram.account.practiceTournament.fetch(practice.practiceTournament);
+      expect(account.questionPool.length).to.equal(questions.length);
+      expect(account.category).to.equal(category);
+    });
+
+    it("should fail to add duplicate or ineligible questions", async () => {
+      await AssertionHelper.assertError(
+        async () => {
+          await addQuestions(practice, [questions[0]]);
+        },
+        "QuestionAlreadyInPool"
+      );
+      await AssertionHelper.assertError(
+        async () => {
+          await addQuestions(practice, offTopic);
+        },
+        "IneligibleQuestion"
+      );
+    });
+
+    it("should fail to create a practice tournament with an overlong category", async () => {
+      await AssertionHelper.assertError(
+        async () => {
+          await createPracticeTournament(testSetup, { category: "x".repeat(51) });
+        },
+        "CategoryTooLong"
+      );
+    });
+
+    it("should fail to start an attempt before the pool is large enough", async () => {
+      const empty = await createPracticeTournament(testSetup, { category, difficulty });
+
+      await AssertionHelper.assertError(
+        async () => {
+          await startAttempt(testSetup, empty, testSetup.users[1]);
+        },
+        "NotEnoughQuestions"
+      );
+    });
+
+    it("should draw a distinct question set from the pool for each attempt", async () => {
+      const player = testSetup.users[1];
+      await startAttempt(testSetup, practice, player);
+
+      const pool = (await testSetup.tournamentProgram.account.practiceTournament.fetch(practice.practiceTournament))
+        .questionPool.map((id) => id.toString());
+      const attempt = await testSetup.tournamentProgram.account.practiceAttempt.fetch(
+        practiceAttemptPda(testSetup, practice, player)
+      );
+      const drawn = attempt.questionIds.map((id) => id.toString());
+
+      expect(drawn.length).to.equal(5);
+      expect(new Set(drawn).size).to.equal(5);
+      drawn.forEach((id) => expect(pool).to.include(id));
+
+      await AssertionHelper.assertError(
+        async () => {
+          await startAttempt(testSetup, practice, player);
+        },
+        "AttemptInProgress"
+      );
+    });
+
+    it("should keep the best attempt on the rolling leaderboard", async () => {
+      const player = testSetup.users[1];
+      const drawn = await testSetup.tournamentProgram.account.practiceAttempt.fetch(
+        practiceAttemptPda(testSetup, practice, player)
+      );
+      const drawnQuestions = drawn.questionIds.map((id) =>
+        PDAHelper.getQuestionPDA(id.toNumber(), testSetup.questionBankProgram.programId)
+      );
+
+      await testSetup.tournamentProgram.methods
+        .submitPracticeAnswers([0, 1, 2, 3, 0].map((answer) => new anchor.BN(answer)))
+        .accounts({
+          practiceTournament: practice.practiceTournament,
+          attempt: practiceAttemptPda(testSetup, practice, player),
+          leaderboard: practice.leaderboard,
+          player: player.publicKey,
+        })
+        .remainingAccounts(questionAccounts(drawnQuestions))
+        .signers([player.keypair])
+        .rpc();
+
+      const attempt = await testSetup.tournamentProgram.account.practiceAttempt.fetch(
+        practiceAttemptPda(testSetup, practice, player)
+      );
+      expect(attempt.startedAt).to.be.null;
+      expect(attempt.bestScore).to.equal(attempt.lastScore);
+
+      const leaderboard = await testSetup.tournamentProgram.account.leaderboard.fetch(practice.leaderboard);
+      expect(leaderboard.entries[0].participant.toString()).to.equal(player.publicKey.toString());
+      expect(leaderboard.entries[0].score).to.equal(attempt.bestScore);
+    });
+  });
+
+  describe("Question Mixes", () => {
+    const difficulty = 2;
+    const mixCategories = ["Mix A", "Mix B", "Mix C", "Mix D", "Mix E"];
+    // Two approved questions per category
+    const bank: Record<string, PublicKey[]> = {};
+
+    const mixOf = (weights: number[]) =>
+      weights.map((weightBps, i) => ({ category: mixCategories[i], difficulty, weightBps }));
+
+    const setMix = (t: TestTournament, mix: ReturnType<typeof mixOf>) =>
+      testSetup.tournamentProgram.methods
+        .setQuestionMix(mix)
+        .accounts({ tournament: t.tournament, organizer: t.organizer.publicKey })
+        .signers([t.organizer.keypair])
+        .rpc();
+
+    before(async () => {
+      for (const category of mixCategories) {
+        bank[category] = await seedApprovedQuestions(testSetup, 2, category, difficulty);
+      }
+    });
+
+    it("should give rounding leftovers to the largest remainders", async () => {
+      // 5 * 33.34% = 1.667 and 5 * 33.33% = 1.6665, so the first two buckets get two
+      const t = await createTestTournament(testSetup, { questionCount: 5 });
+      await setMix(t, mixOf([3334, 3333, 3333]));
+
+      await AssertionHelper.assertError(
+        async () => {
+          await selectQuestions(testSetup, t, [...bank["Mix A"], bank["Mix B"][0], ...bank["Mix C"]]);
+        },
+        "InsufficientQuestionsForBucket"
+      );
+
+      const selected = [...bank["Mix A"], ...bank["Mix B"], bank["Mix C"][0]];
+      await selectQuestions(testSetup, t, selected);
+
+      const tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.questionIds.length).to.equal(5);
+    });
+
+    it("should spread several leftovers in bucket order on ties", async () => {
+      // 7 * 20% = 1.4 for every bucket, leaving two questions for the first two buckets
+      const t = await createTestTournament(testSetup, { questionCount: 7 });
+      await setMix(t, mixOf([2000, 2000, 2000, 2000, 2000]));
+
+      await selectQuestions(testSetup, t, [
+        ...bank["Mix A"],
+        ...bank["Mix B"],
+        bank["Mix C"][0],
+        bank["Mix D"][0],
+        bank["Mix E"][0],
+      ]);
+
+      const tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.questionIds.length).to.equal(7);
+    });
+
+    it("should fail with weights that do not add up to 100%", async () => {
+      const t = await createTestTournament(testSetup);
+
+      await AssertionHelper.assertError(
+        async () => {
+          await setMix(t, mixOf([5000, 4000]));
+        },
+        "InvalidQuestionMix"
+      );
+    });
+
+    it("should fail to select the same question twice", async () => {
+      const t = await createTestTournament(testSetup, { questionCount: 5 });
+      await setMix(t, mixOf([6000, 4000]));
+
+      await AssertionHelper.assertError(
+        async () => {
+          await selectQuestions(testSetup, t, [bank["Mix A"][0], bank["Mix A"][0], bank["Mix A"][1], ...bank["Mix B"]]);
+        },
+        "DuplicateQuestion"
+      );
+    });
+
+    it("should not start a mixed tournament before its questions are selected", async () => {
+      const t = await createTestTournament(testSetup, { startIn: 3600 });
+      await setMix(t, mixOf([5000, 5000]));
+      await registerPlayer(testSetup, t, testSetup.users[1]);
+      await registerPlayer(testSetup, t, testSetup.users[2]);
+
+      await AssertionHelper.assertError(
+        async () => {
+          await startTournament(testSetup, t);
+        },
+        "QuestionsNotSelected"
+      );
+    });
+  });
+
+  describe("Organizer Registry", () => {
+    let organizer: TestUser;
+    let organizerProfile: PublicKey;
+    let t: TestTournament;
+
+    const cancel = (target: TestTournament) =>
+      testSetup.tournamentProgram.methods
+        .cancelTournament()
+        .accounts({
+          tournament: target.tournament,
+          organizerProfile: organizerProfilePda(testSetup, target.organizer),
+          organizer: target.organizer.publicKey,
+        })
+        .signers([target.organizer.keypair])
+        .rpc();
+
+    const withdraw = (amount: number) =>
+      testSetup.tournamentProgram.methods
+        .withdrawOrganizerBond(new anchor.BN(amount))
+        .accounts({ organizerProfile, organizer: organizer.publicKey, systemProgram: SystemProgram.programId })
+        .signers([organizer.keypair])
+        .rpc();
+
+    before(async () => {
+      organizer = await testSetup.createTestUser("Bonded Organizer", 5);
+      organizerProfile = organizerProfilePda(testSetup, organizer);
+      await registerOrganizer(testSetup, organizer);
+
+      t = await createTestTournament(testSetup, { organizer, startIn: 3600, entryFee: 1_000_000 });
+      await registerPlayer(testSetup, t, testSetup.users[1]);
+      await registerPlayer(testSetup, t, testSetup.users[2]);
+    });
+
+    it("should fail to register with less than the minimum bond", async () => {
+      const newcomer = await testSetup.createTestUser("Underfunded Organizer", 2);
+
+      await AssertionHelper.assertError(
+        async () => {
+          await registerOrganizer(testSetup, newcomer, ORGANIZER_MIN_BOND - 1);
+        },
+        "InsufficientOrganizerBond"
+      );
+    });
+
+    it("should lock the bond while the organizer has active tournaments", async () => {
+      const profile = await testSetup.tournamentProgram.account.organizerProfile.fetch(organizerProfile);
+      expect(profile.bond.toNumber()).to.equal(ORGANIZER_MIN_BOND);
+      expect(profile.tournamentsCreated).to.equal(1);
+      expect(profile.activeTournaments).to.equal(1);
+
+      await AssertionHelper.assertError(
+        async () => {
+          await withdraw(1);
+        },
+        "OrganizerHasActiveTournaments"
+      );
+    });
+
+    it("should let only the manager authority verify organizers", async () => {
+      const { tournamentManagerPda } = PDAHelper.getTournamentManagerPDAs(testSetup.tournamentProgram.programId);
+      const setVerified = (signer: TestUser) =>
+        testSetup.tournamentProgram.methods
+          .setOrganizerVerified(true)
+          .accounts({ tournamentManager: tournamentManagerPda, organizerProfile, authority: signer.publicKey })
+          .signers([signer.keypair])
+          .rpc();
+
+      await AssertionHelper.assertError(
+        async () => {
+          await setVerified(organizer);
+        },
+        "UnauthorizedAuthority"
+      );
+
+      await setVerified(testSetup.authority);
+      const profile = await testSetup.tournamentProgram.account.organizerProfile.fetch(organizerProfile);
+      expect(profile.verified).to.be.true;
+    });
+
+    it("should refund every participant of a cancelled tournament", async () => {
+      await cancel(t);
+
+      for (const player of [testSetup.users[1], testSetup.users[2]]) {
+        const balanceBefore = await getAccount(testSetup.provider.connection, player.tokenAccount);
+        await refundParticipant(testSetup, t, player);
+        const balanceAfter = await getAccount(testSetup.provider.connection, player.tokenAccount);
+        expect(Number(balanceAfter.amount - balanceBefore.amount)).to.equal(1_000_000);
+      }
+
+      const tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.status).to.deep.equal({ cancelled: {} });
+      expect(tournament.pendingRefunds).to.equal(0);
+
+      await AssertionHelper.assertError(
+        async () => {
+          await refundParticipant(testSetup, t, testSetup.users[1]);
+        },
+        "AlreadyRefunded"
+      );
+    });
+
+    it("should not slash an organizer who refunded in time", async () => {
+      const { tournamentManagerPda } = PDAHelper.getTournamentManagerPDAs(testSetup.tournamentProgram.programId);
+
+      await AssertionHelper.assertError(
+        async () => {
+          await testSetup.tournamentProgram.methods
+            .slashOrganizer()
+            .accounts({
+              tournamentManager: tournamentManagerPda,
+              tournament: t.tournament,
+              organizerProfile,
+              treasury: testSetup.authority.publicKey,
+            })
+            .rpc();
+        },
+        "OrganizerNotSlashable"
+      );
+    });
+
+    it("should close a cancelled tournament and its registrations", async () => {
+      await closeRegistration(testSetup, t, testSetup.users[1], { tournament: t.tournament });
+      await closeTournament(testSetup, t);
+
+      const archive = await testSetup.tournamentProgram.account.tournamentArchive.fetch(archivePda(testSetup, t));
+      expect(archive.status).to.deep.equal({ cancelled: {} });
+      expect(archive.participants).to.equal(2);
+      expect(await testSetup.provider.connection.getAccountInfo(t.tournament)).to.be.null;
+
+      // Registrations left behind close against the archive
+      await closeRegistration(testSetup, t, testSetup.users[2], { archive: archivePda(testSetup, t) });
+    });
+
+    it("should release the bond once nothing is active", async () => {
+      const balanceBefore = await testSetup.provider.connection.getBalance(organizer.publicKey);
+      await withdraw(ORGANIZER_MIN_BOND);
+
+      const profile = await testSetup.tournamentProgram.account.organizerProfile.fetch(organizerProfile);
+      expect(profile.bond.toNumber()).to.equal(0);
+      expect(profile.tournamentsCancelled).to.equal(1);
+      expect(await testSetup.provider.connection.getBalance(organizer.publicKey)).to.be.above(balanceBefore);
+    });
+  });
+
+  describe("Sponsored Tournaments", () => {
+    const prizeAmount = 5_000_000;
+    let sponsor: TestUser;
+    let t: TestTournament;
+
+    const sponsorship = (overrides: Partial<{ prizeAmount: number; requiredAmount: number }> = {}) => ({
+      name: "Acme Trivia",
+      uri: "https://example.com/acme.json",
+      logoHash: Array(32).fill(7),
+      prizeAmount: new anchor.BN(overrides.prizeAmount ?? prizeAmount),
+      requiredMint: testSetup.tokenMint,
+      requiredAmount: new anchor.BN(overrides.requiredAmount ?? 1),
+    });
+
+    const sponsorTournament = (target: TestTournament, data = sponsorship()) =>
+      testSetup.tournamentProgram.methods
+        .sponsorTournament(data)
+        .accounts({
+          tournament: target.tournament,
+          organizer: target.organizer.publicKey,
+          sponsor: sponsor.publicKey,
+          sponsorTokenAccount: sponsor.tokenAccount,
+          tournamentVault: target.tournamentVault,
+          tokenMint: target.tokenMint,
+          tokenProgram: target.tokenProgram,
+        })
+        .signers([target.organizer.keypair, sponsor.keypair])
+        .rpc();
+
+    const reclaim = (signer: TestUser) =>
+      testSetup.tournamentProgram.methods
+        .reclaimSponsorFunds()
+        .accounts({
+          tournament: t.tournament,
+          sponsor: signer.publicKey,
+          sponsorTokenAccount: signer.tokenAccount,
+          tournamentVault: t.tournamentVault,
+          tokenMint: t.tokenMint,
+          rewardPool: null,
+          rewardVault: null,
+          rewardVaultToken: null,
+          rewardDistributorProgram: null,
+          tokenProgram: t.tokenProgram,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([signer.keypair])
+        .rpc();
+
+    before(async () => {
+      sponsor = await testSetup.createTestUser("Sponsor", 2);
+      t = await createTestTournament(testSetup, { startIn: 3600 });
+    });
+
+    it("should escrow the sponsor's prize pool and branding", async () => {
+      await sponsorTournament(t);
+
+      const tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.prizePool.toNumber()).to.equal(prizeAmount);
+      expect(tournament.sponsor.sponsor.toString()).to.equal(sponsor.publicKey.toString());
+      expect(tournament.sponsor.name).to.equal("Acme Trivia");
+      expect(tournament.sponsor.fundedAmount.toNumber()).to.equal(prizeAmount);
+
+      await AssertionHelper.assertError(
+        async () => {
+          await sponsorTournament(t);
+        },
+        "AlreadySponsored"
+      );
+    });
+
+    it("should keep sponsored tournaments free to enter", async () => {
+      const paid = await createTestTournament(testSetup, { entryFee: 1_000_000 });
+      await AssertionHelper.assertError(
+        async () => {
+          await sponsorTournament(paid);
+        },
+        "SponsoredTournamentNotFree"
+      );
+
+      await AssertionHelper.assertError(
+        async () => {
+          await updateTournament(testSetup, t, {
+            description: null,
+            startTime: null,
+            maxParticipants: null,
+            entryFee: new anchor.BN(1_000_000),
+            category: null,
+            difficulty: null,
+          });
+        },
+        "SponsoredTournamentNotFree"
+      );
+    });
+
+    it("should require entrants to hold the sponsor's token", async () => {
+      const player = testSetup.users[1];
+
+      await AssertionHelper.assertError(
+        async () => {
+          await registerPlayer(testSetup, t, player);
+        },
+        "SponsorRequirementNotMet"
+      );
+
+      await registerPlayer(testSetup, t, player, { sponsorTokenAccount: player.tokenAccount });
+      const tournament = await testSetup.tournamentProgram.account.tournament.fetch(t.tournament);
+      expect(tournament.currentParticipants).to.equal(1);
+    });
+
+    it("should return the funds of a cancelled tournament to the sponsor only", async () => {
+      await testSetup.tournamentProgram.methods
+        .cancelTournament()
+        .accounts({
+          tournament: t.tournament,
+          organizerProfile: organizerProfilePda(testSetup, t.organizer),
+          organizer: t.organizer.publicKey,
+        })
+        .signers([t.organizer.keypair])
+        .rpc();
+      await refundParticipant(testSetup, t, testSetup.users[1]);
+      await closeRegistration(testSetup, t, testSetup.users[1], { tournament: t.tournament });
+
+      await AssertionHelper.assertError(
+        async () => {
+          await closeTournament(testSetup, t);
+        },
+        "SponsorFundsNotReclaimed"
+      );
+      await AssertionHelper.assertError(
+        async () => {
+          await reclaim(testSetup.users[2]);
+        },
+        "UnauthorizedSponsor"
+      );
+
+      const balanceBefore = await getAccount(testSetup.provider.connection, sponsor.tokenAccount);
+      await reclaim(sponsor);
+      const balanceAfter = await getAccount(testSetup.provider.connection, sponsor.tokenAccount);
+      expect(Number(balanceAfter.amount - balanceBefore.amount)).to.equal(prizeAmount);
+
+      await closeTournament(testSetup, t);
+    });
+  });
+});
+
+// ============================================================================
+// Fixtures
+// ============================================================================
+
+/** Transfer fee charged by mints from `createToken2022Mint` */
+const TRANSFER_FEE_BPS = 100;
+
+interface TestTournament {
+  id: number;
+  tournament: PublicKey;
+  leaderboard: PublicKey;
+  tournamentVault: PublicKey;
+  tokenMint: PublicKey;
+  tokenProgram: PublicKey;
+  organizer: TestUser;
+  /** Selected question accounts, in `question_ids` order */
+  questions: PublicKey[];
+}
+
+interface TestTournamentOptions {
+  organizer?: TestUser;
+  entryFee?: number;
+  maxParticipants?: number;
+  startIn?: number;
+  duration?: number;
+  questionCount?: number;
+  category?: string | null;
+  difficulty?: number | null;
+  crankBounty?: number;
+  minRating?: number | null;
+  maxRating?: number | null;
+  questions?: PublicKey[];
+  tokenMint?: PublicKey;
+  tokenProgram?: PublicKey;
+}
+
+/** Create a free tournament, organized by the authority, starting in a minute unless overridden */
+async function createTestTournament(
+  setup: TestSetup,
+  options: TestTournamentOptions = {}
+): Promise<TestTournament> {
+  const programId = setup.tournamentProgram.programId;
+  const { tournamentManagerPda } = PDAHelper.getTournamentManagerPDAs(programId);
+  const manager = await setup.tournamentProgram.account.tournamentManagerState.fetch(tournamentManagerPda);
+  const id = manager.tournamentCount.toNumber();
+
+  const organizer = options.organizer ?? setup.authority;
+  const tournament = PDAHelper.getTournamentPDA(id, programId);
+  const [leaderboard] = PublicKey.findProgramAddressSync(
+    [Buffer.from("leaderboard"), tournament.toBuffer()],
+    programId
+  );
+  const [tournamentVault] = PublicKey.findProgramAddressSync(
+    [Buffer.from("tournament_vault"), tournament.toBuffer()],
+    programId
+  );
+  const tokenMint = options.tokenMint ?? setup.tokenMint;
+  const tokenProgram = options.tokenProgram ?? TOKEN_PROGRAM_ID;
+
+  await setup.tournamentProgram.methods
+    .createTournament(
+      `Test Tournament ${id}`,
+      "Tournament created by a test fixture",
+      new anchor.BN(options.entryFee ?? 0),
+      new anchor.BN(0),
+      options.maxParticipants ?? 10,
+      new anchor.BN(TimeHelper.future(options.startIn ?? 60)),
+      new anchor.BN(options.duration ?? 120),
+      options.questionCount ?? 5,
+      options.category === undefined ? "General" : options.category,
+      options.difficulty === undefined ? null : options.difficulty,
+      new anchor.BN(options.crankBounty ?? 0),
+      options.minRating ?? null,
+      options.maxRating ?? null
+    )
+    .accounts({
+      tournament,
+      tournamentManager: tournamentManagerPda,
+      leaderboard,
+      tournamentVault,
+      tokenMint,
+      organizerProfile: organizerProfilePda(setup, organizer),
+      organizer: organizer.publicKey,
+      tokenProgram,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([organizer.keypair])
+    .rpc();
+
+  const t: TestTournament = { id, tournament, leaderboard, tournamentVault, tokenMint, tokenProgram, organizer, questions: [] };
+  if (options.questions) {
+    await selectQuestions(setup, t, options.questions);
+    t.questions = options.questions;
+  }
+  return t;
+}
+
+/** Register `player`, paying any entry fee from their token account */
+async function registerPlayer(
+  setup: TestSetup,
+  t: TestTournament,
+  player: TestUser,
+  options: { participantTokenAccount?: PublicKey; sponsorTokenAccount?: PublicKey } = {}
+): Promise<PublicKey> {
+  const programId = setup.tournamentProgram.programId;
+  const { tournamentManagerPda } = PDAHelper.getTournamentManagerPDAs(programId);
+  const registration = PDAHelper.getRegistrationPDA(t.tournament, player.publicKey, programId);
+
+  await setup.tournamentProgram.methods
+    .registerForTournament()
+    .accounts({
+      tournamentManager: tournamentManagerPda,
+      tournament: t.tournament,
+      registration,
+      playerProfile: playerProfilePda(setup, player),
+      participant: player.publicKey,
+      participantTokenAccount: options.participantTokenAccount ?? player.tokenAccount,
+      tournamentVault: t.tournamentVault,
+      tokenMint: t.tokenMint,
+      sponsorTokenAccount: options.sponsorTokenAccount ?? null,
+      tokenProgram: t.tokenProgram,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([player.keypair])
+    .rpc();
+
+  return registration;
+}
+
+/**
+ * Create a 6-decimal Token-2022 mint with a single extension. Transfer fee
+ * mints charge `TRANSFER_FEE_BPS`; permanent delegate mints are unsupported.
+ */
+async function createToken2022Mint(setup: TestSetup, extension: ExtensionType): Promise<PublicKey> {
+  const connection = setup.provider.connection;
+  const payer = setup.authority.keypair;
+  const mint = Keypair.generate();
+  const space = getMintLen([extension]);
+
+  const transaction = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: payer.publicKey,
+      newAccountPubkey: mint.publicKey,
+      space,
+      lamports: await connection.getMinimumBalanceForRentExemption(space),
+      programId: TOKEN_2022_PROGRAM_ID,
+    }),
+    extension === ExtensionType.TransferFeeConfig
+      ? createInitializeTransferFeeConfigInstruction(
+          mint.publicKey,
+          payer.publicKey,
+          payer.publicKey,
+          TRANSFER_FEE_BPS,
+          BigInt(1_000_000_000),
+          TOKEN_2022_PROGRAM_ID
+        )
+      : createInitializePermanentDelegateInstruction(mint.publicKey, payer.publicKey, TOKEN_2022_PROGRAM_ID),
+    createInitializeMintInstruction(mint.publicKey, 6, payer.publicKey, null, TOKEN_2022_PROGRAM_ID)
+  );
+  await sendAndConfirmTransaction(connection, transaction, [payer, mint]);
+
+  return mint.publicKey;
+}
+
+/** Create `owner`'s associated account for a Token-2022 mint and mint `amount` into it */
+async function fundToken2022Account(
+  setup: TestSetup,
+  mint: PublicKey,
+  owner: PublicKey,
+  amount: number
+): Promise<PublicKey> {
+  const connection = setup.provider.connection;
+  const payer = setup.authority.keypair;
+
+  const tokenAccount = await createAssociatedTokenAccount(
+    connection,
+    payer,
+    mint,
+    owner,
+    undefined,
+    TOKEN_2022_PROGRAM_ID
+  );
+  await mintTo(connection, payer, mint, tokenAccount, payer, amount, [], undefined, TOKEN_2022_PROGRAM_ID);
+
+  return tokenAccount;
+}
+
+async function startTournament(setup: TestSetup, t: TestTournament): Promise<void> {
+  await setup.tournamentProgram.methods
+    .startTournament()
+    .accounts({ tournament: t.tournament, organizer: t.organizer.publicKey })
+    .signers([t.organizer.keypair])
+    .rpc();
+}
+
+async function endTournament(setup: TestSetup, t: TestTournament): Promise<void> {
+  await setup.tournamentProgram.methods
+    .endTournament()
+    .accounts({ tournament: t.tournament, organizer: t.organizer.publicKey })
+    .signers([t.organizer.keypair])
+    .rpc();
+}
+
+const DISPUTE_BOND = 0.05 * LAMPORTS_PER_SOL;
+
+async function submitAnswers(
+  setup: TestSetup,
+  t: TestTournament,
+  player: TestUser,
+  answers: number[],
+  options: { session?: Keypair } = {}
+): Promise<void> {
+  const registration = PDAHelper.getRegistrationPDA(t.tournament, player.publicKey, setup.tournamentProgram.programId);
+  const signer = options.session ?? player.keypair;
+
+  await setup.tournamentProgram.methods
+    .submitAnswers(answers.map((answer) => new anchor.BN(answer)))
+    .accounts({
+      tournament: t.tournament,
+      registration,
+      leaderboard: t.leaderboard,
+      playerProfile: playerProfilePda(setup, player),
+      participant: player.publicKey,
+      gameSession: options.session ? gameSessionPda(setup, registration) : null,
+      signer: signer.publicKey,
+    })
+    .remainingAccounts(questionAccounts(t.questions))
+    .signers([signer])
+    .rpc();
+}
+
+function disputePda(setup: TestSetup, t: TestTournament, questionIndex: number, disputer: TestUser): PublicKey {
+  const [dispute] = PublicKey.findProgramAddressSync(
+    [Buffer.from("dispute"), t.tournament.toBuffer(), Buffer.from([questionIndex]), disputer.publicKey.toBuffer()],
+    setup.tournamentProgram.programId
+  );
+  return dispute;
+}
+
+async function openDispute(
+  setup: TestSetup,
+  t: TestTournament,
+  questionIndex: number,
+  disputer: TestUser
+): Promise<void> {
+  await setup.tournamentProgram.methods
+    .openDispute(questionIndex)
+    .accounts({
+      tournament: t.tournament,
+      registration: PDAHelper.getRegistrationPDA(t.tournament, disputer.publicKey, setup.tournamentProgram.programId),
+      dispute: disputePda(setup, t, questionIndex, disputer),
+      disputer: disputer.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([disputer.keypair])
+    .rpc();
+}
+
+async function updateTournament(setup: TestSetup, t: TestTournament, update: any): Promise<void> {
+  await setup.tournamentProgram.methods
+    .updateTournament(update)
+    .accounts({ tournament: t.tournament, organizer: t.organizer.publicKey })
+    .signers([t.organizer.keypair])
+    .rpc();
+}
+
+function playerProfilePda(setup: TestSetup, player: TestUser): PublicKey {
+  const [playerProfile] = PublicKey.findProgramAddressSync(
+    [Buffer.from("player"), player.publicKey.toBuffer()],
+    setup.tournamentProgram.programId
+  );
+  return playerProfile;
+}
+
+/** reward_distributor pool ID bit reserved for tournament prize pools */
+const TOURNAMENT_POOL_ID_FLAG = new anchor.BN(1).shln(63);
+
+/** Accounts of the reward_distributor pool a tournament settles into */
+function prizePoolPdas(setup: TestSetup, t: TestTournament) {
+  const programId = setup.rewardProgram.programId;
+  const poolId = TOURNAMENT_POOL_ID_FLAG.or(new anchor.BN(t.id));
+  const [rewardPool] = PublicKey.findProgramAddressSync(
+    [Buffer.from("pool"), poolId.toArrayLike(Buffer, "le", 8)],
+    programId
+  );
+  const [rewardVault] = PublicKey.findProgramAddressSync(
+    [Buffer.from("reward_vault"), poolId.toArrayLike(Buffer, "le", 8)],
+    programId
+  );
+  const rewardVaultToken = getAssociatedTokenAddressSync(t.tokenMint, rewardVault, true, t.tokenProgram);
+  return { poolId, rewardPool, rewardVault, rewardVaultToken };
+}
+
+function prizeClaimPda(setup: TestSetup, poolId: anchor.BN, winner: TestUser): PublicKey {
+  const [userClaim] = PublicKey.findProgramAddressSync(
+    [Buffer.from("claim"), poolId.toArrayLike(Buffer, "le", 8), winner.publicKey.toBuffer()],
+    setup.rewardProgram.programId
+  );
+  return userClaim;
+}
+
+/**
+ * Settle a tournament. Each winner contributes its player profile and its
+ * reward_distributor claim account as remaining accounts.
+ */
+async function distributePrizes(
+  setup: TestSetup,
+  t: TestTournament,
+  winners: TestUser[],
+  amounts: number[]
+): Promise<void> {
+  const { poolId, rewardPool, rewardVault, rewardVaultToken } = prizePoolPdas(setup, t);
+  const remainingAccounts = winners.flatMap((winner) => [
+    { pubkey: playerProfilePda(setup, winner), isWritable: true, isSigner: false },
+    { pubkey: prizeClaimPda(setup, poolId, winner), isWritable: true, isSigner: false },
+  ]);
+
+  await setup.tournamentProgram.methods
+    .distributePrizes(
+      winners.map((winner) => winner.publicKey),
+      amounts.map((amount) => new anchor.BN(amount))
+    )
+    .accounts({
+      tournament: t.tournament,
+      organizer: t.organizer.publicKey,
+      leaderboard: t.leaderboard,
+      tournamentVault: t.tournamentVault,
+      tokenMint: t.tokenMint,
+      organizerProfile: organizerProfilePda(setup, t.organizer),
+      rewardPool,
+      rewardVault,
+      rewardVaultToken,
+      rewardDistributorProgram: setup.rewardProgram.programId,
+      tokenProgram: t.tokenProgram,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .remainingAccounts(remainingAccounts)
+    .signers([t.organizer.keypair])
+    .rpc();
+}
+
+function gameSessionPda(setup: TestSetup, registration: PublicKey): PublicKey {
+  const [gameSession] = PublicKey.findProgramAddressSync(
+    [Buffer.from("session"), registration.toBuffer()],
+    setup.tournamentProgram.programId
+  );
+  return gameSession;
+}
+
+async function createGameSession(
+  setup: TestSetup,
+  t: TestTournament,
+  player: TestUser,
+  sessionKey: PublicKey,
+  duration: number
+): Promise<void> {
+  const registration = PDAHelper.getRegistrationPDA(t.tournament, player.publicKey, setup.tournamentProgram.programId);
+
+  await setup.tournamentProgram.methods
+    .createGameSession(sessionKey, new anchor.BN(duration))
+    .accounts({
+      tournament: t.tournament,
+      registration,
+      gameSession: gameSessionPda(setup, registration),
+      participant: player.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([player.keypair])
+    .rpc();
+}
+
+async function saveAnswers(
+  setup: TestSetup,
+  t: TestTournament,
+  player: TestUser,
+  startIndex: number,
+  answers: number[]
+): Promise<void> {
+  await setup.tournamentProgram.methods
+    .saveAnswers(startIndex, answers.map((answer) => new anchor.BN(answer)))
+    .accounts({
+      tournament: t.tournament,
+      registration: PDAHelper.getRegistrationPDA(t.tournament, player.publicKey, setup.tournamentProgram.programId),
+      participant: player.publicKey,
+      gameSession: null,
+      signer: player.publicKey,
+    })
+    .signers([player.keypair])
+    .rpc();
+}
+
+async function finalizeAnswers(setup: TestSetup, t: TestTournament, player: TestUser): Promise<void> {
+  await setup.tournamentProgram.methods
+    .finalizeAnswers()
+    .accounts({
+      tournament: t.tournament,
+      registration: PDAHelper.getRegistrationPDA(t.tournament, player.publicKey, setup.tournamentProgram.programId),
+      leaderboard: t.leaderboard,
+      playerProfile: playerProfilePda(setup, player),
+      participant: player.publicKey,
+      gameSession: null,
+      signer: player.publicKey,
+    })
+    .remainingAccounts(questionAccounts(t.questions))
+    .signers([player.keypair])
+    .rpc();
+}
+
+function archivePda(setup: TestSetup, t: TestTournament): PublicKey {
+  const [archive] = PublicKey.findProgramAddressSync(
+    [Buffer.from("archive"), new anchor.BN(t.id).toArrayLike(Buffer, "le", 8)],
+    setup.tournamentProgram.programId
+  );
+  return archive;
+}
+
+/** Close a registration against its live tournament or, once closed, its archive */
+async function closeRegistration(
+  setup: TestSetup,
+  t: TestTournament,
+  player: TestUser,
+  against: { tournament?: PublicKey; archive?: PublicKey }
+): Promise<void> {
+  await setup.tournamentProgram.methods
+    .closeRegistration()
+    .accounts({
+      tournament: against.tournament ?? null,
+      archive: against.archive ?? null,
+      registration: PDAHelper.getRegistrationPDA(t.tournament, player.publicKey, setup.tournamentProgram.programId),
+      participant: player.publicKey,
+    })
+    .signers([player.keypair])
+    .rpc();
+}
+
+interface TestPracticeTournament {
+  id: number;
+  practiceTournament: PublicKey;
+  leaderboard: PublicKey;
+  organizer: TestUser;
+}
+
+async function createPracticeTournament(
+  setup: TestSetup,
+  options: { organizer?: TestUser; duration?: number; category?: string | null; difficulty?: number | null } = {}
+): Promise<TestPracticeTournament> {
+  const programId = setup.tournamentProgram.programId;
+  const organizer = options.organizer ?? setup.authority;
+  const { tournamentManagerPda } = PDAHelper.getTournamentManagerPDAs(programId);
+  const manager = await setup.tournamentProgram.account.tournamentManagerState.fetch(tournamentManagerPda);
+  const id = manager.tournamentCount.toNumber();
+
+  const [practiceTournament] = PublicKey.findProgramAddressSync(
+    [Buffer.from("practice"), new anchor.BN(id).toArrayLike(Buffer, "le", 8)],
+    programId
+  );
+  const [leaderboard] = PublicKey.findProgramAddressSync(
+    [Buffer.from("leaderboard"), practiceTournament.toBuffer()],
+    programId
+  );
+
+  await setup.tournamentProgram.methods
+    .createPracticeTournament(
+      "Practice Round",
+      new anchor.BN(options.duration ?? 600),
+      5,
+      options.category ?? null,
+      options.difficulty ?? null
+    )
+    .accounts({
+      practiceTournament,
+      tournamentManager: tournamentManagerPda,
+      leaderboard,
+      organizer: organizer.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([organizer.keypair])
+    .rpc();
+
+  return { id, practiceTournament, leaderboard, organizer };
+}
+
+function practiceAttemptPda(setup: TestSetup, practice: TestPracticeTournament, player: TestUser): PublicKey {
+  const [attempt] = PublicKey.findProgramAddressSync(
+    [Buffer.from("attempt"), practice.practiceTournament.toBuffer(), player.publicKey.toBuffer()],
+    setup.tournamentProgram.programId
+  );
+  return attempt;
+}
+
+async function startAttempt(setup: TestSetup, practice: TestPracticeTournament, player: TestUser): Promise<void> {
+  const { tournamentManagerPda } = PDAHelper.getTournamentManagerPDAs(setup.tournamentProgram.programId);
+
+  await setup.tournamentProgram.methods
+    .startAttempt()
+    .accounts({
+      tournamentManager: tournamentManagerPda,
+      practiceTournament: practice.practiceTournament,
+      attempt: practiceAttemptPda(setup, practice, player),
+      player: player.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([player.keypair])
+    .rpc();
+}
+
+/**
+ * Submit `count` multiple choice questions (option 0 is correct) to the
+ * question bank and approve them with five votes each. Fresh voters carry no
+ * weight under the default tiered weighting, so square-root weighting is used
+ * while seeding. Returns the question accounts.
+ */
+async function seedApprovedQuestions(
+  setup: TestSetup,
+  count: number,
+  category: string,
+  difficulty: number
+): Promise<PublicKey[]> {
+  const programId = setup.questionBankProgram.programId;
+  const { questionBankPda } = PDAHelper.getQuestionBankPDAs(programId);
+  const [bankConfig] = PublicKey.findProgramAddressSync([Buffer.from("bank_config")], programId);
+  const [curationPool] = PublicKey.findProgramAddressSync([Buffer.from("curation_pool")], programId);
+  const submitter = setup.users[0];
+  const voters = setup.users.slice(1, 6);
+  const curator = setup.curators[0];
+  const questions: PublicKey[] = [];
+
+  const setVoteWeighting = (voteWeighting: object, weightedQuorum: number) =>
+    setup.questionBankProgram.methods
+      .updateBankConfig({
+        minSubmitReputation: null,
+        initialReputation: null,
+        minVotes: null,
+        votingPeriod: null,
+        weightedQuorum: new anchor.BN(weightedQuorum),
+        voteWeighting,
+        voteTiers: null,
+        voteStake: null,
+        stakeSlashBps: null,
+        submissionReward: null,
+        voteReward: null,
+        approvalReward: null,
+        rejectionPenalty: null,
+        maxQuestionLen: null,
+        maxOptionLen: null,
+        maxCategoryLen: null,
+        minDifficulty: null,
+        maxDifficulty: null,
+        submissionBond: null,
+        maxPendingQuestions: null,
+        flagBond: null,
+        minReviewVotes: null,
+      })
+      .accounts({ questionBank: questionBankPda, bankConfig, authority: setup.authority.publicKey })
+      .signers([setup.authority.keypair])
+      .rpc();
+
+  await setVoteWeighting({ squareRoot: {} }, 50);
+
+  for (let i = 0; i < count; i++) {
+    const questionBank = await setup.questionBankProgram.account.questionBank.fetch(questionBankPda);
+    const questionId = questionBank.totalQuestions.toNumber();
+    const question = PDAHelper.getQuestionPDA(questionId, programId);
+
+    await setup.questionBankProgram.methods
+      .submitQuestion({
+        questionText: `${category} question ${questionId}`,
+        options: ["A", "B", "C", "D"],
+        questionType: { multipleChoice: { correctIndex: 0 } },
+        category,
+        difficulty,
+      })
+      .accounts({
+        question,
+        questionBank: questionBankPda,
+        bankConfig,
+        userReputation: PDAHelper.getUserReputationPDA(submitter.publicKey, programId),
+        submitter: submitter.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([submitter.keypair])
+      .rpc();
+
+    for (const voter of voters) {
+      const [voteRecord] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), question.toBuffer(), voter.publicKey.toBuffer()],
+        programId
+      );
+
+      await setup.questionBankProgram.methods
+        .voteOnQuestion({ approve: {} })
+        .accounts({
+          question,
+          voteRecord,
+          bankConfig,
+          userReputation: PDAHelper.getUserReputationPDA(voter.publicKey, programId),
+          voterTokenAccount: null,
+          stakeVault: null,
+          stakeMint: null,
+          voter: voter.publicKey,
+          tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([voter.keypair])
+        .rpc();
+    }
+
+    await setup.questionBankProgram.methods
+      .finalizeQuestion(new anchor.BN(questionId))
+      .accounts({
+        question,
+        questionBank: questionBankPda,
+        bankConfig,
+        submitterReputation: PDAHelper.getUserReputationPDA(submitter.publicKey, programId),
+        curationPool,
+        submitter: submitter.publicKey,
+        curator: curator.publicKey,
+      })
+      .signers([curator.keypair])
+      .rpc();
+
+    questions.push(question);
+  }
+
+  await setVoteWeighting({ tiered: {} }, 5);
+  return questions;
+}
+
+/** Select a tournament's questions; pass them grouped by mix bucket in order */
+async function selectQuestions(setup: TestSetup, t: TestTournament, questions: PublicKey[]): Promise<void> {
+  await setup.tournamentProgram.methods
+    .selectQuestions()
+    .accounts({ tournament: t.tournament, organizer: t.organizer.publicKey })
+    .remainingAccounts(questions.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })))
+    .signers([t.organizer.keypair])
+    .rpc();
+}
+
+const ORGANIZER_MIN_BOND = 1 * LAMPORTS_PER_SOL;
+
+function organizerProfilePda(setup: TestSetup, organizer: TestUser): PublicKey {
+  const [organizerProfile] = PublicKey.findProgramAddressSync(
+    [Buffer.from("organizer"), organizer.publicKey.toBuffer()],
+    setup.tournamentProgram.programId
+  );
+  return organizerProfile;
+}
+
+async function registerOrganizer(
+  setup: TestSetup,
+  organizer: TestUser,
+  bond: number = ORGANIZER_MIN_BOND
+): Promise<void> {
+  await setup.tournamentProgram.methods
+    .registerOrganizer(new anchor.BN(bond))
+    .accounts({
+      organizerProfile: organizerProfilePda(setup, organizer),
+      organizer: organizer.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([organizer.keypair])
+    .rpc();
+}
+
+async function refundParticipant(setup: TestSetup, t: TestTournament, player: TestUser): Promise<void> {
+  await setup.tournamentProgram.methods
+    .refundParticipant()
+    .accounts({
+      tournament: t.tournament,
+      registration: PDAHelper.getRegistrationPDA(t.tournament, player.publicKey, setup.tournamentProgram.programId),
+      tournamentVault: t.tournamentVault,
+      participantTokenAccount: player.tokenAccount,
+      tokenMint: t.tokenMint,
+      tokenProgram: t.tokenProgram,
+    })
+    .rpc();
+}
+
+async function closeTournament(setup: TestSetup, t: TestTournament): Promise<void> {
+  await setup.tournamentProgram.methods
+    .closeTournament()
+    .accounts({
+      tournament: t.tournament,
+      leaderboard: t.leaderboard,
+      tournamentVault: t.tournamentVault,
+      organizerTokenAccount: t.organizer.tokenAccount,
+      tokenMint: t.tokenMint,
+      organizerProfile: organizerProfilePda(setup, t.organizer),
+      archive: archivePda(setup, t),
+      organizer: t.organizer.publicKey,
+      tokenProgram: t.tokenProgram,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([t.organizer.keypair])
+    .rpc();
+}
+
+/** Read-only remaining accounts for question accounts, in order */
+function questionAccounts(questions: PublicKey[]) {
+  return questions.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false }));
+}